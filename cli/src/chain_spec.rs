@@ -27,8 +27,8 @@ use riochain_runtime::{
 use riochain_runtime::{
     BalancesConfig, CouncilConfig, DemocracyConfig, GenesisConfig, ImOnlineConfig, IndicesConfig,
     OracleConfig, RioAssetsConfig, RioGatewayConfig, RioPaymentFeeConfig, RioRootConfig,
-    SessionConfig, SessionKeys, SocietyConfig, StakingConfig, SudoConfig, SystemConfig,
-    TechnicalCommitteeConfig, VestingConfig, WASM_BINARY,
+    RioVestingConfig, SessionConfig, SessionKeys, SocietyConfig, StakingConfig, SudoConfig,
+    SystemConfig, TechnicalCommitteeConfig, VestingConfig, WASM_BINARY,
 };
 
 // Note this is the URL for the telemetry server
@@ -386,7 +386,8 @@ fn asset_init() -> Vec<(
                 name: b"Locked Rio Fuel Token".to_vec(),
                 decimals: 12,
                 desc: b"Locked Rio Fuel Token".to_vec(),
-                chain: rio_assets::Chain::Rio,
+                chain: rio_assets::Chain::Rio.id(),
+                min_balance: 0,
             },
             rio_assets::Restriction::Transferable.into(),
             vec![],
@@ -398,7 +399,8 @@ fn asset_init() -> Vec<(
                 name: b"MANTRA DAO Token".to_vec(),
                 decimals: 12,
                 desc: b"MANTRA DAO Token".to_vec(),
-                chain: rio_assets::Chain::Rio,
+                chain: rio_assets::Chain::Rio.id(),
+                min_balance: 0,
             },
             rio_assets::Restrictions::none(),
             vec![],
@@ -410,7 +412,8 @@ fn asset_init() -> Vec<(
                 name: b"RBTC token".to_vec(),
                 decimals: 8,
                 desc: b"Bitcoin in RioChain".to_vec(),
-                chain: rio_assets::Chain::Bitcoin,
+                chain: rio_assets::Chain::Bitcoin.id(),
+                min_balance: 0,
             },
             rio_assets::Restrictions::none(),
             vec![],
@@ -422,7 +425,8 @@ fn asset_init() -> Vec<(
                 name: b"RLTC token".to_vec(),
                 decimals: 8,
                 desc: b"Litecoin in RioChain".to_vec(),
-                chain: rio_assets::Chain::Litecoin,
+                chain: rio_assets::Chain::Litecoin.id(),
+                min_balance: 0,
             },
             rio_assets::Restrictions::none(),
             vec![],
@@ -434,7 +438,8 @@ fn asset_init() -> Vec<(
                 name: b"RETH token".to_vec(),
                 decimals: 18,
                 desc: b"Ether in RioChain".to_vec(),
-                chain: rio_assets::Chain::Ethereum,
+                chain: rio_assets::Chain::Ethereum.id(),
+                min_balance: 0,
             },
             rio_assets::Restrictions::none(),
             vec![],
@@ -446,7 +451,8 @@ fn asset_init() -> Vec<(
                 name: b"RUSDT token".to_vec(),
                 decimals: 6,
                 desc: b"USDT in RioChain".to_vec(),
-                chain: rio_assets::Chain::Ethereum,
+                chain: rio_assets::Chain::Ethereum.id(),
+                min_balance: 0,
             },
             rio_assets::Restrictions::none(),
             vec![],
@@ -559,6 +565,7 @@ fn testnet_genesis(
         rio_root: Some(RioRootConfig {
             managers: vec![root_key.clone()]
         }),
+        rio_vesting: Some(RioVestingConfig { vesting: vec![] }),
         orml_vesting: Some(VestingConfig { vesting: vec![] }),
         orml_oracle: Some(OracleConfig {
             members: Default::default(), // initialized by OperatorMembership
@@ -675,6 +682,7 @@ fn beta_genesis(
         rio_root: Some(RioRootConfig {
             managers: vec![root_key.clone()]
         }),
+        rio_vesting: Some(RioVestingConfig { vesting: vec![] }),
         orml_vesting: Some(VestingConfig { vesting: vec![] }),
         orml_oracle: Some(OracleConfig {
             members: Default::default(), // initialized by OperatorMembership
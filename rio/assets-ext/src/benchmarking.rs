@@ -0,0 +1,408 @@
+//! Benchmarks for the rio-assets-ext module, the wrappers are charged with the weights of
+//! rio-assets.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::{Currency, EnsureOrigin, Get, UnfilteredDispatchable};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{One, Saturating, Zero};
+use sp_std::vec;
+
+use orml_traits::MultiCurrency;
+use rio_assets::benchmarking::{
+    benchmark_asset_info, benchmark_chain_info, benchmark_pause_reason, create_benchmark_asset,
+    fund, fund_native, issue_benchmark_asset,
+};
+
+type Assets<T> = rio_assets::Module<T>;
+
+const SEED: u32 = 0;
+
+benchmarks! {
+    _ { }
+
+    transfer_with_memo {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let dest: T::AccountId = account("dest", 0, SEED);
+        let currency_id = create_benchmark_asset::<T>();
+        let amount: T::Balance = 1000u32.into();
+        fund::<T>(currency_id, &caller, amount);
+        let memo = vec![b'M'; rio_protocol::MEMO_BYTES_LEN];
+    }: _(RawOrigin::Signed(caller.clone()), T::Lookup::unlookup(dest.clone()), currency_id, amount, memo)
+    verify {
+        assert_eq!(<Assets<T> as MultiCurrency<_>>::free_balance(currency_id, &dest), amount);
+    }
+
+    batch_transfer {
+        let c in 1 .. rio_protocol::MAX_BATCH_TRANSFER as u32;
+
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let currency_id = create_benchmark_asset::<T>();
+        let amount: T::Balance = 1000u32.into();
+        fund::<T>(currency_id, &caller, amount.saturating_mul(c.into()));
+
+        let legs = (0..c)
+            .map(|i| {
+                let dest: T::AccountId = account("dest", i, SEED);
+                (T::Lookup::unlookup(dest), currency_id, amount)
+            })
+            .collect::<Vec<_>>();
+    }: _(RawOrigin::Signed(caller.clone()), legs)
+    verify {
+        assert!(<Assets<T> as MultiCurrency<_>>::free_balance(currency_id, &caller).is_zero());
+    }
+
+    approve {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let spender: T::AccountId = account("spender", 0, SEED);
+        let currency_id = create_benchmark_asset::<T>();
+        let amount: T::Balance = 1000u32.into();
+    }: _(RawOrigin::Signed(caller.clone()), T::Lookup::unlookup(spender.clone()), currency_id, amount)
+    verify {
+        assert_eq!(Assets::<T>::allowance(currency_id, &caller, &spender), amount);
+    }
+
+    increase_allowance {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let spender: T::AccountId = account("spender", 0, SEED);
+        let currency_id = create_benchmark_asset::<T>();
+        let amount: T::Balance = 1000u32.into();
+        Assets::<T>::approve(
+            RawOrigin::Signed(caller.clone()).into(),
+            T::Lookup::unlookup(spender.clone()),
+            currency_id,
+            amount,
+        )?;
+    }: _(RawOrigin::Signed(caller.clone()), T::Lookup::unlookup(spender.clone()), currency_id, amount)
+    verify {
+        assert_eq!(Assets::<T>::allowance(currency_id, &caller, &spender), amount.saturating_mul(2u32.into()));
+    }
+
+    decrease_allowance {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let spender: T::AccountId = account("spender", 0, SEED);
+        let currency_id = create_benchmark_asset::<T>();
+        let amount: T::Balance = 1000u32.into();
+        Assets::<T>::approve(
+            RawOrigin::Signed(caller.clone()).into(),
+            T::Lookup::unlookup(spender.clone()),
+            currency_id,
+            amount,
+        )?;
+    }: _(RawOrigin::Signed(caller.clone()), T::Lookup::unlookup(spender.clone()), currency_id, amount)
+    verify {
+        assert!(Assets::<T>::allowance(currency_id, &caller, &spender).is_zero());
+    }
+
+    transfer_from {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let dest: T::AccountId = account("dest", 0, SEED);
+        let currency_id = create_benchmark_asset::<T>();
+        let amount: T::Balance = 1000u32.into();
+        fund::<T>(currency_id, &owner, amount);
+        Assets::<T>::approve(
+            RawOrigin::Signed(owner.clone()).into(),
+            T::Lookup::unlookup(caller.clone()),
+            currency_id,
+            amount,
+        )?;
+    }: _(
+        RawOrigin::Signed(caller.clone()),
+        T::Lookup::unlookup(owner.clone()),
+        T::Lookup::unlookup(dest.clone()),
+        currency_id,
+        amount
+    )
+    verify {
+        assert_eq!(<Assets<T> as MultiCurrency<_>>::free_balance(currency_id, &dest), amount);
+        assert!(Assets::<T>::allowance(currency_id, &owner, &caller).is_zero());
+    }
+
+    create {
+        let currency_id = T::UserAssetIdStart::get();
+    }: _(RawOrigin::Root, currency_id, benchmark_asset_info::<T>())
+    verify {
+        assert!(Assets::<T>::asset_info_of(currency_id).is_some());
+    }
+
+    issue {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let currency_id = T::UserAssetIdStart::get();
+        fund_native::<T>(&caller);
+    }: _(RawOrigin::Signed(caller.clone()), currency_id, benchmark_asset_info::<T>())
+    verify {
+        assert!(Assets::<T>::asset_ownership(currency_id).is_some());
+    }
+
+    mint {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let dest: T::AccountId = account("dest", 0, SEED);
+        let currency_id = issue_benchmark_asset::<T>(&caller);
+        let amount: T::Balance = 1000u32.into();
+    }: _(RawOrigin::Signed(caller), currency_id, T::Lookup::unlookup(dest.clone()), amount)
+    verify {
+        assert_eq!(<Assets<T> as MultiCurrency<_>>::free_balance(currency_id, &dest), amount);
+    }
+
+    burn {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let currency_id = issue_benchmark_asset::<T>(&caller);
+        let amount: T::Balance = 1000u32.into();
+        fund::<T>(currency_id, &caller, amount);
+    }: _(RawOrigin::Signed(caller.clone()), currency_id, amount)
+    verify {
+        assert!(Assets::<T>::total_issuance(currency_id).is_zero());
+    }
+
+    set_issuer {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let issuer: T::AccountId = account("issuer", 0, SEED);
+        let currency_id = issue_benchmark_asset::<T>(&caller);
+    }: _(RawOrigin::Signed(caller), currency_id, T::Lookup::unlookup(issuer.clone()))
+    verify {
+        assert_eq!(Assets::<T>::asset_ownership(currency_id).map(|o| o.issuer), Some(issuer));
+    }
+
+    transfer_ownership {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let currency_id = issue_benchmark_asset::<T>(&caller);
+        T::NativeCurrency::make_free_balance_be(&owner, T::NativeCurrency::minimum_balance());
+    }: _(RawOrigin::Signed(caller), currency_id, T::Lookup::unlookup(owner.clone()))
+    verify {
+        assert_eq!(Assets::<T>::asset_ownership(currency_id).map(|o| o.owner), Some(owner));
+    }
+
+    destroy {
+        let c in 0 .. 1000;
+
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let currency_id = issue_benchmark_asset::<T>(&caller);
+        for i in 0..c {
+            let spender: T::AccountId = account("spender", i, SEED);
+            Assets::<T>::approve(
+                RawOrigin::Signed(caller.clone()).into(),
+                T::Lookup::unlookup(spender),
+                currency_id,
+                1u32.into(),
+            )?;
+        }
+    }: _(RawOrigin::Signed(caller), currency_id, c)
+    verify {
+        assert!(Assets::<T>::asset_info_of(currency_id).is_none());
+    }
+
+    update_asset_info {
+        let currency_id = create_benchmark_asset::<T>();
+        let mut asset_info = benchmark_asset_info::<T>();
+        asset_info.decimals = 18;
+    }: _(RawOrigin::Root, currency_id, asset_info.clone())
+    verify {
+        assert_eq!(Assets::<T>::asset_info_of(currency_id), Some(asset_info));
+    }
+
+    set_max_supply {
+        let currency_id = create_benchmark_asset::<T>();
+        let max: T::Balance = 1000u32.into();
+    }: _(RawOrigin::Root, currency_id, Some(max))
+    verify {
+        assert_eq!(Assets::<T>::max_supply(currency_id), Some(max));
+    }
+
+    update_restriction {
+        let currency_id = create_benchmark_asset::<T>();
+    }: _(RawOrigin::Root, currency_id, Restrictions::frozen())
+    verify {
+        assert_eq!(Assets::<T>::asset_restrictions(currency_id), Restrictions::frozen());
+    }
+
+    set_account_restrictions {
+        let who: T::AccountId = account("who", 0, SEED);
+        let currency_id = create_benchmark_asset::<T>();
+        let origin = T::ManagerOrigin::successful_origin();
+        let call = Call::<T>::set_account_restrictions(
+            T::Lookup::unlookup(who.clone()),
+            currency_id,
+            Restrictions::frozen(),
+        );
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert_eq!(Assets::<T>::account_restrictions(currency_id, &who), Restrictions::frozen());
+    }
+
+    freeze_account {
+        let who: T::AccountId = account("who", 0, SEED);
+        let currency_id = create_benchmark_asset::<T>();
+        let origin = T::ManagerOrigin::successful_origin();
+        let call = Call::<T>::freeze_account(T::Lookup::unlookup(who.clone()), currency_id);
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert!(Assets::<T>::is_frozen(currency_id, &who));
+    }
+
+    thaw_account {
+        let who: T::AccountId = account("who", 0, SEED);
+        let currency_id = create_benchmark_asset::<T>();
+        Assets::<T>::freeze_account(
+            T::ManagerOrigin::successful_origin(),
+            T::Lookup::unlookup(who.clone()),
+            currency_id,
+        )?;
+        let origin = T::ManagerOrigin::successful_origin();
+        let call = Call::<T>::thaw_account(T::Lookup::unlookup(who.clone()), currency_id);
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert!(!Assets::<T>::is_frozen(currency_id, &who));
+    }
+
+    offline_asset {
+        let currency_id = create_benchmark_asset::<T>();
+    }: _(RawOrigin::Root, currency_id)
+    verify {
+        assert!(!Assets::<T>::online(currency_id));
+    }
+
+    online_asset {
+        let currency_id = create_benchmark_asset::<T>();
+        Assets::<T>::offline_asset(RawOrigin::Root.into(), currency_id)?;
+    }: _(RawOrigin::Root, currency_id)
+    verify {
+        assert!(Assets::<T>::online(currency_id));
+    }
+
+    pause_asset {
+        let currency_id = create_benchmark_asset::<T>();
+        let pause = Pause::Restrict(Restrictions::frozen());
+    }: _(RawOrigin::Root, currency_id, pause, benchmark_pause_reason())
+    verify {
+        assert_eq!(Assets::<T>::asset_restrictions(currency_id), Restrictions::frozen());
+    }
+
+    unpause_asset {
+        let currency_id = create_benchmark_asset::<T>();
+        let pause = Pause::Restrict(Restrictions::frozen());
+        let start = frame_system::Module::<T>::block_number() + One::one();
+        Module::<T>::schedule_pause(
+            RawOrigin::Root.into(),
+            currency_id,
+            pause,
+            benchmark_pause_reason(),
+            start,
+            One::one(),
+        )?;
+        Module::<T>::start_scheduled_pause(
+            RawOrigin::Root.into(),
+            currency_id,
+            pause,
+            benchmark_pause_reason(),
+        )?;
+    }: _(RawOrigin::Root, currency_id)
+    verify {
+        assert_eq!(Assets::<T>::asset_restrictions(currency_id), Restrictions::none());
+        assert!(Module::<T>::scheduled_pause(currency_id).is_none());
+    }
+
+    schedule_pause {
+        let currency_id = create_benchmark_asset::<T>();
+        let pause = Pause::Restrict(Restrictions::frozen());
+        let start = frame_system::Module::<T>::block_number() + One::one();
+    }: _(RawOrigin::Root, currency_id, pause, benchmark_pause_reason(), start, One::one())
+    verify {
+        assert!(Module::<T>::scheduled_pause(currency_id).is_some());
+    }
+
+    cancel_scheduled_pause {
+        let currency_id = create_benchmark_asset::<T>();
+        let pause = Pause::Restrict(Restrictions::frozen());
+        let start = frame_system::Module::<T>::block_number() + One::one();
+        Module::<T>::schedule_pause(
+            RawOrigin::Root.into(),
+            currency_id,
+            pause,
+            benchmark_pause_reason(),
+            start,
+            One::one(),
+        )?;
+        // the pause has started
+        frame_system::Module::<T>::set_block_number(start);
+        Module::<T>::start_scheduled_pause(
+            RawOrigin::Root.into(),
+            currency_id,
+            pause,
+            benchmark_pause_reason(),
+        )?;
+    }: _(RawOrigin::Root, currency_id)
+    verify {
+        assert!(Assets::<T>::paused_asset(currency_id).is_none());
+    }
+
+    start_destroy_asset {
+        let currency_id = create_benchmark_asset::<T>();
+    }: _(RawOrigin::Root, currency_id, DestroyPolicy::BurnAndRecord)
+    verify {
+        assert_eq!(Assets::<T>::destroying_asset(currency_id), Some(DestroyPolicy::BurnAndRecord));
+    }
+
+    destroy_asset {
+        let c in 1 .. 1000;
+
+        let currency_id = create_benchmark_asset::<T>();
+        let amount: T::Balance = 1000u32.into();
+        for i in 0..c {
+            let who: T::AccountId = account("holder", i, SEED);
+            fund::<T>(currency_id, &who, amount);
+        }
+        Assets::<T>::start_destroy_asset(RawOrigin::Root.into(), currency_id, DestroyPolicy::BurnAndRecord)?;
+    }: _(RawOrigin::Root, currency_id, c)
+    verify {
+        assert!(Assets::<T>::asset_info_of(currency_id).is_none());
+    }
+
+    snapshot {
+        let currency_id = create_benchmark_asset::<T>();
+        let origin = T::ManagerOrigin::successful_origin();
+        let call = Call::<T>::snapshot(currency_id);
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert_eq!(Assets::<T>::current_snapshot_id(currency_id), 1);
+    }
+
+    unlock_expired {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let currency_id = create_benchmark_asset::<T>();
+        let amount: T::Balance = 1000u32.into();
+        fund::<T>(currency_id, &caller, amount);
+
+        let until = frame_system::Module::<T>::block_number() + One::one();
+        Assets::<T>::set_lock_with_expiry(
+            *b"rio/benc",
+            currency_id,
+            &caller,
+            amount,
+            until,
+            vec![b'R'; rio_protocol::LOCK_REASON_LEN],
+        )?;
+        frame_system::Module::<T>::set_block_number(until);
+    }: _(RawOrigin::Signed(caller.clone()), currency_id)
+    verify {
+        assert!(Assets::<T>::locks(&caller, currency_id).is_empty());
+    }
+
+    register_chain {
+        let chain_id = ChainId::max_value();
+    }: _(RawOrigin::Root, chain_id, benchmark_chain_info())
+    verify {
+        assert_eq!(Assets::<T>::chain_info(chain_id), Some(benchmark_chain_info()));
+    }
+
+    update_chain {
+        let chain_id = Chain::Bitcoin.id();
+    }: _(RawOrigin::Root, chain_id, benchmark_chain_info())
+    verify {
+        assert_eq!(Assets::<T>::chain_info(chain_id), Some(benchmark_chain_info()));
+    }
+}
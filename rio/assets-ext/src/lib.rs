@@ -1,35 +1,94 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use sp_runtime::DispatchResult;
+mod benchmarking;
+
+use codec::{Decode, Encode};
+use sp_runtime::{
+    traits::{Dispatchable, Saturating, StaticLookup, Zero},
+    DispatchResult, RuntimeDebug,
+};
 use sp_std::prelude::*;
 
-use frame_support::{decl_error, decl_event, decl_module, decl_storage};
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage, ensure,
+    traits::{
+        schedule::{DispatchTime, Named as ScheduleNamed, HARD_DEADLINE},
+        Get,
+    },
+    transactional,
+    weights::Weight,
+    Parameter,
+};
+use frame_system::{ensure_root, RawOrigin};
 
 use rio_assets::*;
+use rio_primitives::Memo;
+
+/// The prefix of the names of the scheduled pause tasks.
+const PAUSE_ID: [u8; 8] = *b"rio/paus";
+
+/// A pause scheduled by `schedule_pause`.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+pub struct ScheduledPause<BlockNumber> {
+    /// the block the asset is paused
+    pub start: BlockNumber,
+    /// the block the asset is unpaused
+    pub end: BlockNumber,
+    /// whether the current pause of the asset is made by the start, only this pause is lifted
+    /// by the end, e.g. not a manual pause before the start
+    pub paused: bool,
+}
 
 /// The module's configuration trait.
 pub trait Trait: frame_system::Trait + rio_assets::Trait {
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
+
+    /// The runtime call dispatched by the scheduler, i.e. the pause calls of this module.
+    type ScheduledCall: Parameter
+        + Dispatchable<Origin = <Self as frame_system::Trait>::Origin>
+        + From<Call<Self>>;
+
+    /// The scheduler of the start and the end of the pauses.
+    type Scheduler: ScheduleNamed<Self::BlockNumber, Self::ScheduledCall, Self::PalletsOrigin>;
+
+    /// The origin of the scheduled calls, they are dispatched as `Root`.
+    type PalletsOrigin: From<RawOrigin<Self::AccountId>>;
 }
 
 decl_error! {
     /// Error for the generic-asset module.
     pub enum Error for Module<T: Trait> {
-
+        /// A pause of the asset is already scheduled
+        PauseAlreadyScheduled,
+        /// No pause of the asset is scheduled
+        PauseNotScheduled,
+        /// The start of the pause is not in the future or the duration is zero
+        InvalidPauseWindow,
+        /// The scheduler failed to schedule the pause
+        ScheduleFailed,
     }
 }
 
 decl_event!(
     pub enum Event<T> where
         <T as frame_system::Trait>::AccountId,
+        <T as frame_system::Trait>::BlockNumber,
+        <T as rio_assets::Trait>::CurrencyId,
     {
         Holder(AccountId),
+        /// A pause of an asset is scheduled. [currency_id, start, end]
+        PauseScheduled(CurrencyId, BlockNumber, BlockNumber),
+        /// A scheduled pause of an asset is cancelled. [currency_id]
+        ScheduledPauseCancelled(CurrencyId),
     }
 );
 
 // This module's storage items.
 decl_storage! {
     trait Store for Module<T: Trait> as RioAssetsExt {
+        /// The pauses scheduled by `schedule_pause`, removed at the end of the pause window.
+        pub ScheduledPauses get(fn scheduled_pause):
+            map hasher(twox_64_concat) T::CurrencyId => Option<ScheduledPause<T::BlockNumber>>;
     }
 }
 
@@ -39,32 +98,181 @@ decl_module! {
 
         fn deposit_event() = default;
 
+        #[weight = T::WeightInfo::transfer_with_memo()
+            .saturating_add(T::OnReceived::on_received_weight())]
+        pub fn transfer_with_memo(
+            origin,
+            dest: <T::Lookup as StaticLookup>::Source,
+            currency_id: T::CurrencyId,
+            #[compact] amount: T::Balance,
+            memo: Memo,
+        ) -> DispatchResult {
+            rio_assets::Module::<T>::transfer_with_memo(origin, dest, currency_id, amount, memo)
+        }
+
+        #[weight = T::WeightInfo::batch_transfer(legs.len() as u32)
+            .saturating_add(T::OnReceived::on_received_weight().saturating_mul(legs.len() as Weight))]
+        pub fn batch_transfer(
+            origin,
+            legs: Vec<(<T::Lookup as StaticLookup>::Source, T::CurrencyId, T::Balance)>,
+        ) -> DispatchResult {
+            rio_assets::Module::<T>::batch_transfer(origin, legs)
+        }
+
+        #[weight = T::WeightInfo::approve()]
+        pub fn approve(
+            origin,
+            spender: <T::Lookup as StaticLookup>::Source,
+            currency_id: T::CurrencyId,
+            #[compact] amount: T::Balance,
+        ) -> DispatchResult {
+            rio_assets::Module::<T>::approve(origin, spender, currency_id, amount)
+        }
+
+        #[weight = T::WeightInfo::increase_allowance()]
+        pub fn increase_allowance(
+            origin,
+            spender: <T::Lookup as StaticLookup>::Source,
+            currency_id: T::CurrencyId,
+            #[compact] amount: T::Balance,
+        ) -> DispatchResult {
+            rio_assets::Module::<T>::increase_allowance(origin, spender, currency_id, amount)
+        }
+
+        #[weight = T::WeightInfo::decrease_allowance()]
+        pub fn decrease_allowance(
+            origin,
+            spender: <T::Lookup as StaticLookup>::Source,
+            currency_id: T::CurrencyId,
+            #[compact] amount: T::Balance,
+        ) -> DispatchResult {
+            rio_assets::Module::<T>::decrease_allowance(origin, spender, currency_id, amount)
+        }
+
+        #[weight = T::WeightInfo::transfer_from()
+            .saturating_add(T::OnReceived::on_received_weight())]
+        pub fn transfer_from(
+            origin,
+            from: <T::Lookup as StaticLookup>::Source,
+            dest: <T::Lookup as StaticLookup>::Source,
+            currency_id: T::CurrencyId,
+            #[compact] amount: T::Balance,
+        ) -> DispatchResult {
+            rio_assets::Module::<T>::transfer_from(origin, from, dest, currency_id, amount)
+        }
+
         /// create a new asset with full permissions granted to whoever make the call
         /// *sudo or proposal approved only*
         #[weight = T::WeightInfo::create()]
         pub fn create(
             origin,
             currency_id: T::CurrencyId,
-            asset_info: AssetInfo,
+            asset_info: AssetInfo<T::Balance>,
         ) -> DispatchResult {
             rio_assets::Module::<T>::create(origin, currency_id, asset_info)
         }
 
+        /// issue a new user asset, the caller would be the owner and the issuer
+        #[weight = T::WeightInfo::issue()]
+        pub fn issue(
+            origin,
+            currency_id: T::CurrencyId,
+            asset_info: AssetInfo<T::Balance>,
+        ) -> DispatchResult {
+            rio_assets::Module::<T>::issue(origin, currency_id, asset_info)
+        }
+
+        #[weight = T::WeightInfo::mint()
+            .saturating_add(T::OnReceived::on_received_weight())]
+        pub fn mint(
+            origin,
+            currency_id: T::CurrencyId,
+            dest: <T::Lookup as StaticLookup>::Source,
+            #[compact] amount: T::Balance,
+        ) -> DispatchResult {
+            rio_assets::Module::<T>::mint(origin, currency_id, dest, amount)
+        }
+
+        #[weight = T::WeightInfo::burn()]
+        pub fn burn(origin, currency_id: T::CurrencyId, #[compact] amount: T::Balance) -> DispatchResult {
+            rio_assets::Module::<T>::burn(origin, currency_id, amount)
+        }
+
+        #[weight = T::WeightInfo::set_issuer()]
+        pub fn set_issuer(
+            origin,
+            currency_id: T::CurrencyId,
+            issuer: <T::Lookup as StaticLookup>::Source,
+        ) -> DispatchResult {
+            rio_assets::Module::<T>::set_issuer(origin, currency_id, issuer)
+        }
+
+        #[weight = T::WeightInfo::transfer_ownership()]
+        pub fn transfer_ownership(
+            origin,
+            currency_id: T::CurrencyId,
+            owner: <T::Lookup as StaticLookup>::Source,
+        ) -> DispatchResult {
+            rio_assets::Module::<T>::transfer_ownership(origin, currency_id, owner)
+        }
+
+        #[weight = T::WeightInfo::destroy((*max_entries).min(T::MaxDestroyAccounts::get()))]
+        pub fn destroy(origin, currency_id: T::CurrencyId, max_entries: u32) -> DispatchResult {
+            rio_assets::Module::<T>::destroy(origin, currency_id, max_entries)
+        }
+
         #[weight = T::WeightInfo::update_asset_info()]
         pub fn update_asset_info(
             origin,
             currency_id: T::CurrencyId,
-            asset_info: AssetInfo,
+            asset_info: AssetInfo<T::Balance>,
         ) -> DispatchResult {
             rio_assets::Module::<T>::update_asset_info(origin, currency_id, asset_info)
         }
 
+        #[weight = T::WeightInfo::set_max_supply()]
+        pub fn set_max_supply(
+            origin,
+            currency_id: T::CurrencyId,
+            max_supply: Option<T::Balance>,
+        ) -> DispatchResult {
+            rio_assets::Module::<T>::set_max_supply(origin, currency_id, max_supply)
+        }
+
         #[weight = T::WeightInfo::update_restriction()]
         pub fn update_restriction(origin, currency_id: T::CurrencyId, restrictions: Restrictions) -> DispatchResult {
             rio_assets::Module::<T>::update_restriction(origin, currency_id, restrictions)?;
             Ok(())
         }
 
+        #[weight = T::WeightInfo::set_account_restrictions()]
+        pub fn set_account_restrictions(
+            origin,
+            who: <T::Lookup as StaticLookup>::Source,
+            currency_id: T::CurrencyId,
+            restrictions: Restrictions,
+        ) -> DispatchResult {
+            rio_assets::Module::<T>::set_account_restrictions(origin, who, currency_id, restrictions)
+        }
+
+        #[weight = T::WeightInfo::freeze_account()]
+        pub fn freeze_account(
+            origin,
+            who: <T::Lookup as StaticLookup>::Source,
+            currency_id: T::CurrencyId,
+        ) -> DispatchResult {
+            rio_assets::Module::<T>::freeze_account(origin, who, currency_id)
+        }
+
+        #[weight = T::WeightInfo::thaw_account()]
+        pub fn thaw_account(
+            origin,
+            who: <T::Lookup as StaticLookup>::Source,
+            currency_id: T::CurrencyId,
+        ) -> DispatchResult {
+            rio_assets::Module::<T>::thaw_account(origin, who, currency_id)
+        }
+
         #[weight = T::WeightInfo::offline_asset()]
         pub fn offline_asset(origin, currency_id: T::CurrencyId) -> DispatchResult {
             rio_assets::Module::<T>::offline_asset(origin, currency_id)?;
@@ -75,7 +283,200 @@ decl_module! {
         pub fn online_asset(origin, currency_id: T::CurrencyId) -> DispatchResult {
             rio_assets::Module::<T>::online_asset(origin, currency_id)
         }
+
+        #[weight = T::WeightInfo::pause_asset()]
+        pub fn pause_asset(
+            origin,
+            currency_id: T::CurrencyId,
+            pause: Pause,
+            reason: PauseReason,
+        ) -> DispatchResult {
+            rio_assets::Module::<T>::pause_asset(origin, currency_id, pause, reason)
+        }
+
+        /// Revert the pause of an asset, the scheduled pause is over if it made this pause and
+        /// its end is cancelled. A scheduled pause which hasn't started is kept.
+        #[weight = T::WeightInfo::unpause_asset()]
+        pub fn unpause_asset(origin, currency_id: T::CurrencyId) -> DispatchResult {
+            rio_assets::Module::<T>::unpause_asset(origin, currency_id)?;
+            if Self::scheduled_pause(currency_id).map_or(false, |scheduled| scheduled.paused) {
+                ScheduledPauses::<T>::remove(currency_id);
+                // fails if the end is being dispatched now
+                let _ = T::Scheduler::cancel_named(Self::pause_task_id(currency_id, true));
+            }
+            Ok(())
+        }
+
+        /// Schedule a pause of an asset for `duration` blocks from block `start`, the asset is
+        /// paused by `start_scheduled_pause` at `start` and unpaused by `end_scheduled_pause` at
+        /// the end, e.g. for a planned maintenance of the origin chain.
+        ///
+        /// The dispatch origin for this call must be `Root`.
+        #[weight = T::WeightInfo::schedule_pause()]
+        #[transactional]
+        pub fn schedule_pause(
+            origin,
+            currency_id: T::CurrencyId,
+            pause: Pause,
+            reason: PauseReason,
+            start: T::BlockNumber,
+            duration: T::BlockNumber,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                rio_assets::Module::<T>::asset_info_of(currency_id).is_some(),
+                rio_assets::Error::<T>::NotExistedAsset
+            );
+            ensure!(
+                reason.text.len() <= rio_protocol::PAUSE_REASON_LEN,
+                rio_assets::Error::<T>::PauseReasonTooLong
+            );
+            ensure!(Self::scheduled_pause(currency_id).is_none(), Error::<T>::PauseAlreadyScheduled);
+            ensure!(
+                start > frame_system::Module::<T>::block_number() && !duration.is_zero(),
+                Error::<T>::InvalidPauseWindow
+            );
+
+            let end = start.saturating_add(duration);
+            Self::schedule(
+                currency_id,
+                false,
+                start,
+                Call::start_scheduled_pause(currency_id, pause, reason),
+            )?;
+            Self::schedule(currency_id, true, end, Call::end_scheduled_pause(currency_id))?;
+            ScheduledPauses::<T>::insert(currency_id, ScheduledPause { start, end, paused: false });
+
+            Self::deposit_event(RawEvent::PauseScheduled(currency_id, start, end));
+            Ok(())
+        }
+
+        /// Cancel the scheduled pause of an asset, the asset is unpaused at once if the pause
+        /// has started and is made by the schedule.
+        ///
+        /// The dispatch origin for this call must be `Root`.
+        #[weight = T::WeightInfo::cancel_scheduled_pause()]
+        #[transactional]
+        pub fn cancel_scheduled_pause(origin, currency_id: T::CurrencyId) -> DispatchResult {
+            ensure_root(origin)?;
+            let scheduled = ScheduledPauses::<T>::take(currency_id)
+                .ok_or(Error::<T>::PauseNotScheduled)?;
+
+            // the tasks which are dispatched already could not be cancelled
+            let _ = T::Scheduler::cancel_named(Self::pause_task_id(currency_id, false));
+            let _ = T::Scheduler::cancel_named(Self::pause_task_id(currency_id, true));
+            if scheduled.paused {
+                rio_assets::Module::<T>::unpause_asset(RawOrigin::Root.into(), currency_id)?;
+            }
+
+            Self::deposit_event(RawEvent::ScheduledPauseCancelled(currency_id));
+            Ok(())
+        }
+
+        /// The start of a scheduled pause, dispatched by the scheduler. Fails if the asset is
+        /// paused already, that pause is not lifted by the end of the schedule.
+        ///
+        /// The dispatch origin for this call must be `Root`.
+        #[weight = T::WeightInfo::pause_asset()
+            .saturating_add(T::DbWeight::get().reads_writes(1, 1))]
+        pub fn start_scheduled_pause(
+            origin,
+            currency_id: T::CurrencyId,
+            pause: Pause,
+            reason: PauseReason,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(Self::scheduled_pause(currency_id).is_some(), Error::<T>::PauseNotScheduled);
+
+            rio_assets::Module::<T>::pause_asset(RawOrigin::Root.into(), currency_id, pause, reason)?;
+            ScheduledPauses::<T>::mutate(currency_id, |scheduled| {
+                if let Some(scheduled) = scheduled {
+                    scheduled.paused = true;
+                }
+            });
+            Ok(())
+        }
+
+        /// The end of a scheduled pause, dispatched by the scheduler. The asset is unpaused only
+        /// if the pause is made by the start of the schedule.
+        ///
+        /// The dispatch origin for this call must be `Root`.
+        #[weight = T::WeightInfo::unpause_asset()
+            .saturating_add(T::DbWeight::get().reads_writes(1, 1))]
+        #[transactional]
+        pub fn end_scheduled_pause(origin, currency_id: T::CurrencyId) -> DispatchResult {
+            ensure_root(origin)?;
+            let scheduled = ScheduledPauses::<T>::take(currency_id)
+                .ok_or(Error::<T>::PauseNotScheduled)?;
+
+            if scheduled.paused {
+                rio_assets::Module::<T>::unpause_asset(RawOrigin::Root.into(), currency_id)?;
+            }
+            Ok(())
+        }
+
+        #[weight = T::WeightInfo::start_destroy_asset()]
+        pub fn start_destroy_asset(
+            origin,
+            currency_id: T::CurrencyId,
+            policy: DestroyPolicy,
+        ) -> DispatchResult {
+            rio_assets::Module::<T>::start_destroy_asset(origin, currency_id, policy)
+        }
+
+        #[weight = T::WeightInfo::destroy_asset((*max_accounts).min(T::MaxDestroyAccounts::get()))]
+        pub fn destroy_asset(
+            origin,
+            currency_id: T::CurrencyId,
+            max_accounts: u32,
+        ) -> DispatchResult {
+            rio_assets::Module::<T>::destroy_asset(origin, currency_id, max_accounts)
+        }
+
+        #[weight = T::WeightInfo::snapshot()]
+        pub fn snapshot(origin, currency_id: T::CurrencyId) -> DispatchResult {
+            rio_assets::Module::<T>::snapshot(origin, currency_id)
+        }
+
+        #[weight = T::WeightInfo::unlock_expired()]
+        pub fn unlock_expired(origin, currency_id: T::CurrencyId) -> DispatchResult {
+            rio_assets::Module::<T>::unlock_expired(origin, currency_id)
+        }
+
+        #[weight = T::WeightInfo::register_chain()]
+        pub fn register_chain(origin, chain_id: ChainId, chain_info: ChainInfo) -> DispatchResult {
+            rio_assets::Module::<T>::register_chain(origin, chain_id, chain_info)
+        }
+
+        #[weight = T::WeightInfo::update_chain()]
+        pub fn update_chain(origin, chain_id: ChainId, chain_info: ChainInfo) -> DispatchResult {
+            rio_assets::Module::<T>::update_chain(origin, chain_id, chain_info)
+        }
     }
 }
 
-impl<T: Trait> Module<T> {}
+impl<T: Trait> Module<T> {
+    /// The name of the task for the start or the end of the scheduled pause of an asset.
+    fn pause_task_id(currency_id: T::CurrencyId, is_end: bool) -> Vec<u8> {
+        (PAUSE_ID, currency_id, is_end).encode()
+    }
+
+    fn schedule(
+        currency_id: T::CurrencyId,
+        is_end: bool,
+        when: T::BlockNumber,
+        call: Call<T>,
+    ) -> DispatchResult {
+        T::Scheduler::schedule_named(
+            Self::pause_task_id(currency_id, is_end),
+            DispatchTime::At(when),
+            None,
+            // the window of a maintenance must be kept
+            HARD_DEADLINE,
+            RawOrigin::Root.into(),
+            call.into(),
+        )
+        .map(|_| ())
+        .map_err(|_| Error::<T>::ScheduleFailed.into())
+    }
+}
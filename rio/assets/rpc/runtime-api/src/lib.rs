@@ -0,0 +1,25 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+
+use sp_runtime::ModuleId;
+use sp_std::prelude::*;
+
+pub use rio_assets::{AccountData, AssetInfo, Discrepancy, SnapshotId};
+
+sp_api::decl_runtime_apis! {
+    pub trait AssetsApi<CurrencyId, AccountId, Balance, BlockNumber> where
+        CurrencyId: Codec,
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        fn balance_at(currency_id: CurrencyId, who: AccountId, snapshot_id: SnapshotId) -> Option<Balance>;
+        fn holders(currency_id: CurrencyId, offset: u32, limit: u32) -> Vec<(AccountId, Balance)>;
+        fn asset_info_at(currency_id: CurrencyId, block: BlockNumber) -> Option<AssetInfo<Balance>>;
+        /// Check the invariants of the balances, it's expensive and only for debugging.
+        fn check_invariants() -> Vec<Discrepancy<AccountId, CurrencyId, Balance>>;
+        /// The balances of all the assets held by the accounts of the modules.
+        fn module_accounts() -> Vec<(ModuleId, AccountId, Vec<(CurrencyId, AccountData<Balance>)>)>;
+    }
+}
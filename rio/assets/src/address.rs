@@ -0,0 +1,219 @@
+//! The validation of the addresses on the origin chains by `AddressFormat`.
+
+use sp_io::hashing::{blake2_512, keccak_256, sha2_256};
+use sp_std::prelude::*;
+
+use crate::types::AddressFormat;
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BECH32_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const BECH32_CONST: u32 = 1;
+const BECH32M_CONST: u32 = 0x2bc8_30a3;
+const SS58_PREFIX: &[u8] = b"SS58PRE";
+/// the P2PKH and P2SH version bytes of the Bitcoin mainnet
+const BITCOIN_VERSIONS: &[u8] = &[0x00, 0x05];
+const BITCOIN_HRP: &[u8] = b"bc";
+/// the P2PKH, P2SH and the legacy P2SH version bytes of the Litecoin mainnet
+const LITECOIN_VERSIONS: &[u8] = &[0x30, 0x32, 0x05];
+const LITECOIN_HRP: &[u8] = b"ltc";
+
+impl AddressFormat {
+    /// Whether `address` is a valid address in this format.
+    pub fn is_valid(&self, address: &[u8]) -> bool {
+        match self {
+            AddressFormat::Any => true,
+            AddressFormat::Bitcoin => {
+                is_base58check(address, BITCOIN_VERSIONS) || is_segwit(address, BITCOIN_HRP)
+            }
+            AddressFormat::Litecoin => {
+                is_base58check(address, LITECOIN_VERSIONS) || is_segwit(address, LITECOIN_HRP)
+            }
+            AddressFormat::Ethereum => is_eip55(address),
+            AddressFormat::Eos => is_eos_name(address),
+            AddressFormat::Ss58(prefix) => is_ss58(address, *prefix),
+        }
+    }
+}
+
+/// decode a base58 string, `None` if there is a character out of the alphabet
+fn base58_decode(input: &[u8]) -> Option<Vec<u8>> {
+    let mut bytes: Vec<u8> = Vec::with_capacity(input.len());
+    for c in input {
+        let mut carry = BASE58_ALPHABET.iter().position(|a| a == c)? as u32;
+        for byte in bytes.iter_mut().rev() {
+            carry += (*byte as u32) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.insert(0, carry as u8);
+            carry >>= 8;
+        }
+    }
+    // the leading '1's are the leading zeros
+    let zeros = input.iter().take_while(|c| **c == b'1').count();
+    let mut decoded = vec![0u8; zeros];
+    decoded.extend(bytes);
+    Some(decoded)
+}
+
+/// a version byte of `versions`, a hash160 and the first 4 bytes of the double sha256 as the
+/// checksum, e.g. the P2PKH and P2SH addresses
+fn is_base58check(address: &[u8], versions: &[u8]) -> bool {
+    if address.len() < 26 || address.len() > 35 {
+        return false;
+    }
+    match base58_decode(address) {
+        Some(decoded) if decoded.len() == 25 => {
+            let (payload, checksum) = decoded.split_at(21);
+            versions.contains(&payload[0]) && sha2_256(&sha2_256(payload))[..4] == *checksum
+        }
+        _ => false,
+    }
+}
+
+fn bech32_polymod(values: &[u8]) -> u32 {
+    const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+    let mut chk: u32 = 1;
+    for v in values {
+        let top = chk >> 25;
+        chk = ((chk & 0x1ff_ffff) << 5) ^ (*v as u32);
+        for (i, g) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= g;
+            }
+        }
+    }
+    chk
+}
+
+/// a segwit address of BIP173 (bech32) for the version 0 or BIP350 (bech32m) for the later
+/// versions with the human readable part `expected_hrp`
+fn is_segwit(address: &[u8], expected_hrp: &[u8]) -> bool {
+    if address.len() < 8 || address.len() > 90 {
+        return false;
+    }
+    // mixed case is not allowed
+    if address.iter().any(u8::is_ascii_lowercase) && address.iter().any(u8::is_ascii_uppercase) {
+        return false;
+    }
+    let address = address.to_ascii_lowercase();
+    let separator = match address.iter().rposition(|c| *c == b'1') {
+        Some(pos) if pos >= 1 && pos + 7 <= address.len() => pos,
+        _ => return false,
+    };
+    let (hrp, data) = (&address[..separator], &address[separator + 1..]);
+    if hrp != expected_hrp {
+        return false;
+    }
+    // a version and the checksum at least, the program is checked below
+    if data.len() <= 6 {
+        return false;
+    }
+    let data = match data
+        .iter()
+        .map(|c| BECH32_CHARSET.iter().position(|a| a == c).map(|v| v as u8))
+        .collect::<Option<Vec<u8>>>()
+    {
+        Some(data) => data,
+        None => return false,
+    };
+
+    let mut values: Vec<u8> = hrp.iter().map(|c| c >> 5).collect();
+    values.push(0);
+    values.extend(hrp.iter().map(|c| c & 31));
+    values.extend(&data);
+    let checksum = bech32_polymod(&values);
+
+    let version = data[0];
+    let expected = match version {
+        0 => BECH32_CONST,
+        1..=16 => BECH32M_CONST,
+        _ => return false,
+    };
+    if checksum != expected {
+        return false;
+    }
+
+    // convert the program from 5 bits to 8 bits, the padding must be zeros
+    let (mut acc, mut bits) = (0u32, 0u32);
+    let mut program: Vec<u8> = Vec::new();
+    for v in &data[1..data.len() - 6] {
+        acc = (acc << 5) | (*v as u32);
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            program.push((acc >> bits) as u8);
+        }
+    }
+    if bits >= 5 || acc & ((1 << bits) - 1) != 0 {
+        return false;
+    }
+    // BIP141: 2 to 40 bytes, 20 or 32 bytes for the version 0
+    match version {
+        0 => program.len() == 20 || program.len() == 32,
+        _ => (2..=40).contains(&program.len()),
+    }
+}
+
+/// a hex address of 20 bytes, the mixed case addresses must match the EIP-55 checksum
+fn is_eip55(address: &[u8]) -> bool {
+    if address.len() != 42 || !address.starts_with(b"0x") {
+        return false;
+    }
+    let hex = &address[2..];
+    if !hex.iter().all(u8::is_ascii_hexdigit) {
+        return false;
+    }
+    let has_lower = hex.iter().any(u8::is_ascii_lowercase);
+    let has_upper = hex.iter().any(u8::is_ascii_uppercase);
+    if !(has_lower && has_upper) {
+        return true;
+    }
+    let hash = keccak_256(&hex.to_ascii_lowercase());
+    hex.iter().enumerate().all(|(i, c)| {
+        let nibble = if i % 2 == 0 {
+            hash[i / 2] >> 4
+        } else {
+            hash[i / 2] & 0x0f
+        };
+        !c.is_ascii_alphabetic() || c.is_ascii_uppercase() == (nibble >= 8)
+    })
+}
+
+/// up to 12 characters of `a-z`, `1-5` and `.`, not ending with `.`
+fn is_eos_name(address: &[u8]) -> bool {
+    !address.is_empty()
+        && address.len() <= 12
+        && address.last() != Some(&b'.')
+        && address
+            .iter()
+            .all(|c| matches!(c, b'a'..=b'z' | b'1'..=b'5' | b'.'))
+}
+
+/// a SS58 address of a 32 bytes account with the one or two bytes network `prefix`
+fn is_ss58(address: &[u8], prefix: u16) -> bool {
+    if address.len() < 47 || address.len() > 50 {
+        return false;
+    }
+    let decoded = match base58_decode(address) {
+        Some(decoded) => decoded,
+        None => return false,
+    };
+    let (prefix_len, network) = match decoded[..] {
+        [b0 @ 0..=63, ..] => (1, b0 as u16),
+        [b0 @ 64..=127, b1, ..] => {
+            let lower = (b0 << 2) | (b1 >> 6);
+            let upper = b1 & 0b0011_1111;
+            (2, (lower as u16) | ((upper as u16) << 8))
+        }
+        _ => return false,
+    };
+    if network != prefix || decoded.len() != prefix_len + 32 + 2 {
+        return false;
+    }
+    let (payload, checksum) = decoded.split_at(prefix_len + 32);
+    let mut preimage = SS58_PREFIX.to_vec();
+    preimage.extend(payload);
+    blake2_512(&preimage)[..2] == *checksum
+}
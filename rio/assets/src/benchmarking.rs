@@ -0,0 +1,655 @@
+//! Benchmarks for the rio-assets module.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::UnfilteredDispatchable;
+use frame_system::RawOrigin;
+use sp_std::vec;
+
+const SEED: u32 = 0;
+const BENCHMARK_LOCK_ID: LockIdentifier = *b"rio/benc";
+
+/// the asset info used by the benchmarks, with the longest texts
+pub fn benchmark_asset_info<T: Trait>() -> AssetInfo<T::Balance> {
+    AssetInfo {
+        chain: Chain::Rio.id(),
+        min_balance: Zero::zero(),
+        symbol: vec![b'B'; rio_protocol::ASSET_SYMBOL_LEN],
+        name: vec![b'N'; rio_protocol::ASSET_NAME_LEN],
+        decimals: 12,
+        desc: vec![b'D'; rio_protocol::ASSET_DESC_LEN],
+    }
+}
+
+/// the chain info used by the benchmarks, with the longest name
+pub fn benchmark_chain_info() -> ChainInfo {
+    ChainInfo {
+        name: vec![b'C'; rio_protocol::CHAIN_NAME_LEN],
+        address_format: AddressFormat::Any,
+        confirmations: 6,
+    }
+}
+
+/// the pause reason used by the benchmarks, with the longest text
+pub fn benchmark_pause_reason() -> PauseReason {
+    PauseReason {
+        code: u32::max_value(),
+        text: vec![b'R'; rio_protocol::PAUSE_REASON_LEN],
+    }
+}
+
+/// create an asset with the first user asset id for benchmarking
+pub fn create_benchmark_asset<T: Trait>() -> T::CurrencyId {
+    let currency_id = T::UserAssetIdStart::get();
+    Module::<T>::create_asset(currency_id, benchmark_asset_info::<T>())
+        .expect("create benchmark asset must success");
+    currency_id
+}
+
+/// issue a user asset with the first user asset id, `owner` is the owner and the issuer
+pub fn issue_benchmark_asset<T: Trait>(owner: &T::AccountId) -> T::CurrencyId {
+    let currency_id = T::UserAssetIdStart::get();
+    fund_native::<T>(owner);
+    Module::<T>::issue(
+        RawOrigin::Signed(owner.clone()).into(),
+        currency_id,
+        benchmark_asset_info::<T>(),
+    )
+    .expect("issue benchmark asset must success");
+    currency_id
+}
+
+/// give `who` enough native currency to reserve the asset deposit
+pub fn fund_native<T: Trait>(who: &T::AccountId) {
+    let amount = T::AssetDeposit::get()
+        .saturating_mul(2u32.into())
+        .saturating_add(T::NativeCurrency::minimum_balance());
+    T::NativeCurrency::make_free_balance_be(who, amount);
+}
+
+/// deposit `amount` of `currency_id` into `who`
+pub fn fund<T: Trait>(currency_id: T::CurrencyId, who: &T::AccountId, amount: T::Balance) {
+    <Module<T> as MultiCurrency<_>>::deposit(currency_id, who, amount)
+        .expect("deposit for benchmarking must success");
+}
+
+/// take `MaxSnapshots` snapshots of `currency_id`, `who` have the checkpoints of all of them
+/// but the latest, so that the next balance change writes the most checkpoints
+fn fill_checkpoints<T: Trait>(currency_id: T::CurrencyId, who: &[&T::AccountId]) {
+    let max = T::MaxSnapshots::get();
+    let amount: T::Balance = 1000u32.into();
+    let checkpoints = (1..max).map(|id| (id, amount)).collect::<Vec<_>>();
+    CurrentSnapshotId::<T>::insert(currency_id, max);
+    for account in who {
+        BalanceCheckpoints::<T>::insert(currency_id, *account, &checkpoints);
+    }
+}
+
+benchmarks! {
+    _ { }
+
+    transfer {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let dest: T::AccountId = account("dest", 0, SEED);
+        let currency_id = create_benchmark_asset::<T>();
+        let amount: T::Balance = 1000u32.into();
+        fund::<T>(currency_id, &caller, amount);
+        fill_checkpoints::<T>(currency_id, &[&caller, &dest]);
+    }: _(RawOrigin::Signed(caller.clone()), T::Lookup::unlookup(dest.clone()), currency_id, amount)
+    verify {
+        assert_eq!(<Module<T> as MultiCurrency<_>>::free_balance(currency_id, &dest), amount);
+    }
+
+    transfer_all {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let dest: T::AccountId = account("dest", 0, SEED);
+        let currency_id = create_benchmark_asset::<T>();
+        let amount: T::Balance = 1000u32.into();
+        fund::<T>(currency_id, &caller, amount);
+    }: _(RawOrigin::Signed(caller.clone()), T::Lookup::unlookup(dest.clone()), currency_id)
+    verify {
+        assert!(<Module<T> as MultiCurrency<_>>::free_balance(currency_id, &caller).is_zero());
+        assert_eq!(<Module<T> as MultiCurrency<_>>::free_balance(currency_id, &dest), amount);
+    }
+
+    transfer_with_memo {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let dest: T::AccountId = account("dest", 0, SEED);
+        let currency_id = create_benchmark_asset::<T>();
+        let amount: T::Balance = 1000u32.into();
+        fund::<T>(currency_id, &caller, amount);
+        let memo = vec![b'M'; rio_protocol::MEMO_BYTES_LEN];
+    }: _(RawOrigin::Signed(caller.clone()), T::Lookup::unlookup(dest.clone()), currency_id, amount, memo)
+    verify {
+        assert_eq!(<Module<T> as MultiCurrency<_>>::free_balance(currency_id, &dest), amount);
+    }
+
+    batch_transfer {
+        let c in 1 .. rio_protocol::MAX_BATCH_TRANSFER as u32;
+
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let currency_id = create_benchmark_asset::<T>();
+        let amount: T::Balance = 1000u32.into();
+        fund::<T>(currency_id, &caller, amount.saturating_mul(c.into()));
+
+        let legs = (0..c)
+            .map(|i| {
+                let dest: T::AccountId = account("dest", i, SEED);
+                (T::Lookup::unlookup(dest), currency_id, amount)
+            })
+            .collect::<Vec<_>>();
+    }: _(RawOrigin::Signed(caller.clone()), legs)
+    verify {
+        assert!(<Module<T> as MultiCurrency<_>>::free_balance(currency_id, &caller).is_zero());
+    }
+
+    approve {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let spender: T::AccountId = account("spender", 0, SEED);
+        let currency_id = create_benchmark_asset::<T>();
+        let amount: T::Balance = 1000u32.into();
+    }: _(RawOrigin::Signed(caller.clone()), T::Lookup::unlookup(spender.clone()), currency_id, amount)
+    verify {
+        assert_eq!(Module::<T>::allowance(currency_id, &caller, &spender), amount);
+    }
+
+    increase_allowance {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let spender: T::AccountId = account("spender", 0, SEED);
+        let currency_id = create_benchmark_asset::<T>();
+        let amount: T::Balance = 1000u32.into();
+        Module::<T>::set_allowance(currency_id, &caller, &spender, amount);
+    }: _(RawOrigin::Signed(caller.clone()), T::Lookup::unlookup(spender.clone()), currency_id, amount)
+    verify {
+        assert_eq!(Module::<T>::allowance(currency_id, &caller, &spender), amount.saturating_mul(2u32.into()));
+    }
+
+    decrease_allowance {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let spender: T::AccountId = account("spender", 0, SEED);
+        let currency_id = create_benchmark_asset::<T>();
+        let amount: T::Balance = 1000u32.into();
+        Module::<T>::set_allowance(currency_id, &caller, &spender, amount);
+    }: _(RawOrigin::Signed(caller.clone()), T::Lookup::unlookup(spender.clone()), currency_id, amount)
+    verify {
+        assert!(Module::<T>::allowance(currency_id, &caller, &spender).is_zero());
+    }
+
+    transfer_from {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let dest: T::AccountId = account("dest", 0, SEED);
+        let currency_id = create_benchmark_asset::<T>();
+        let amount: T::Balance = 1000u32.into();
+        fund::<T>(currency_id, &owner, amount);
+        Module::<T>::set_allowance(currency_id, &owner, &caller, amount);
+    }: _(
+        RawOrigin::Signed(caller.clone()),
+        T::Lookup::unlookup(owner.clone()),
+        T::Lookup::unlookup(dest.clone()),
+        currency_id,
+        amount
+    )
+    verify {
+        assert_eq!(<Module<T> as MultiCurrency<_>>::free_balance(currency_id, &dest), amount);
+        assert!(Module::<T>::allowance(currency_id, &owner, &caller).is_zero());
+    }
+
+    create {
+        let currency_id = T::UserAssetIdStart::get();
+    }: _(RawOrigin::Root, currency_id, benchmark_asset_info::<T>())
+    verify {
+        assert!(Module::<T>::asset_info_of(currency_id).is_some());
+    }
+
+    issue {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let currency_id = T::UserAssetIdStart::get();
+        fund_native::<T>(&caller);
+    }: _(RawOrigin::Signed(caller.clone()), currency_id, benchmark_asset_info::<T>())
+    verify {
+        assert!(Module::<T>::asset_ownership(currency_id).is_some());
+    }
+
+    mint {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let dest: T::AccountId = account("dest", 0, SEED);
+        let currency_id = issue_benchmark_asset::<T>(&caller);
+        let amount: T::Balance = 1000u32.into();
+    }: _(RawOrigin::Signed(caller), currency_id, T::Lookup::unlookup(dest.clone()), amount)
+    verify {
+        assert_eq!(<Module<T> as MultiCurrency<_>>::free_balance(currency_id, &dest), amount);
+    }
+
+    burn {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let currency_id = issue_benchmark_asset::<T>(&caller);
+        let amount: T::Balance = 1000u32.into();
+        fund::<T>(currency_id, &caller, amount);
+    }: _(RawOrigin::Signed(caller.clone()), currency_id, amount)
+    verify {
+        assert!(Module::<T>::total_issuance(currency_id).is_zero());
+    }
+
+    set_issuer {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let issuer: T::AccountId = account("issuer", 0, SEED);
+        let currency_id = issue_benchmark_asset::<T>(&caller);
+    }: _(RawOrigin::Signed(caller), currency_id, T::Lookup::unlookup(issuer.clone()))
+    verify {
+        assert_eq!(Module::<T>::asset_ownership(currency_id).map(|o| o.issuer), Some(issuer));
+    }
+
+    transfer_ownership {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let currency_id = issue_benchmark_asset::<T>(&caller);
+        T::NativeCurrency::make_free_balance_be(&owner, T::NativeCurrency::minimum_balance());
+    }: _(RawOrigin::Signed(caller), currency_id, T::Lookup::unlookup(owner.clone()))
+    verify {
+        assert_eq!(Module::<T>::asset_ownership(currency_id).map(|o| o.owner), Some(owner));
+    }
+
+    destroy {
+        let c in 0 .. 1000;
+
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let currency_id = issue_benchmark_asset::<T>(&caller);
+        for i in 0..c {
+            let spender: T::AccountId = account("spender", i, SEED);
+            Module::<T>::set_allowance(currency_id, &caller, &spender, 1u32.into());
+        }
+    }: _(RawOrigin::Signed(caller), currency_id, c)
+    verify {
+        assert!(Module::<T>::asset_info_of(currency_id).is_none());
+    }
+
+    update_asset_info {
+        let currency_id = create_benchmark_asset::<T>();
+        let mut asset_info = benchmark_asset_info::<T>();
+        asset_info.decimals = 18;
+    }: _(RawOrigin::Root, currency_id, asset_info.clone())
+    verify {
+        assert_eq!(Module::<T>::asset_info_of(currency_id), Some(asset_info));
+    }
+
+    set_max_supply {
+        let currency_id = create_benchmark_asset::<T>();
+        let max: T::Balance = 1000u32.into();
+    }: _(RawOrigin::Root, currency_id, Some(max))
+    verify {
+        assert_eq!(Module::<T>::max_supply(currency_id), Some(max));
+    }
+
+    update_restriction {
+        let currency_id = create_benchmark_asset::<T>();
+    }: _(RawOrigin::Root, currency_id, Restrictions::frozen())
+    verify {
+        assert_eq!(Module::<T>::asset_restrictions(currency_id), Restrictions::frozen());
+    }
+
+    set_account_restrictions {
+        let who: T::AccountId = account("who", 0, SEED);
+        let currency_id = create_benchmark_asset::<T>();
+        let origin = T::ManagerOrigin::successful_origin();
+        let call = Call::<T>::set_account_restrictions(
+            T::Lookup::unlookup(who.clone()),
+            currency_id,
+            Restrictions::frozen(),
+        );
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert_eq!(Module::<T>::account_restrictions(currency_id, &who), Restrictions::frozen());
+    }
+
+    freeze_account {
+        let who: T::AccountId = account("who", 0, SEED);
+        let currency_id = create_benchmark_asset::<T>();
+        let origin = T::ManagerOrigin::successful_origin();
+        let call = Call::<T>::freeze_account(T::Lookup::unlookup(who.clone()), currency_id);
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert!(Module::<T>::is_frozen(currency_id, &who));
+    }
+
+    thaw_account {
+        let who: T::AccountId = account("who", 0, SEED);
+        let currency_id = create_benchmark_asset::<T>();
+        FrozenAccounts::<T>::insert(currency_id, &who, true);
+        let origin = T::ManagerOrigin::successful_origin();
+        let call = Call::<T>::thaw_account(T::Lookup::unlookup(who.clone()), currency_id);
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert!(!Module::<T>::is_frozen(currency_id, &who));
+    }
+
+    offline_asset {
+        let currency_id = create_benchmark_asset::<T>();
+    }: _(RawOrigin::Root, currency_id)
+    verify {
+        assert!(!Module::<T>::online(currency_id));
+    }
+
+    online_asset {
+        let currency_id = create_benchmark_asset::<T>();
+        Online::<T>::remove(currency_id);
+    }: _(RawOrigin::Root, currency_id)
+    verify {
+        assert!(Module::<T>::online(currency_id));
+    }
+
+    pause_asset {
+        let currency_id = create_benchmark_asset::<T>();
+        let pause = Pause::Restrict(Restrictions::frozen());
+    }: _(RawOrigin::Root, currency_id, pause, benchmark_pause_reason())
+    verify {
+        assert_eq!(Module::<T>::asset_restrictions(currency_id), Restrictions::frozen());
+    }
+
+    unpause_asset {
+        let currency_id = create_benchmark_asset::<T>();
+        let pause = Pause::Restrict(Restrictions::frozen());
+        Module::<T>::pause_asset(RawOrigin::Root.into(), currency_id, pause, benchmark_pause_reason())?;
+    }: _(RawOrigin::Root, currency_id)
+    verify {
+        assert_eq!(Module::<T>::asset_restrictions(currency_id), Restrictions::none());
+    }
+
+    start_destroy_asset {
+        let currency_id = create_benchmark_asset::<T>();
+    }: _(RawOrigin::Root, currency_id, DestroyPolicy::BurnAndRecord)
+    verify {
+        assert_eq!(Module::<T>::destroying_asset(currency_id), Some(DestroyPolicy::BurnAndRecord));
+    }
+
+    destroy_asset {
+        let c in 1 .. 1000;
+
+        let currency_id = create_benchmark_asset::<T>();
+        let amount: T::Balance = 1000u32.into();
+        for i in 0..c {
+            let who: T::AccountId = account("holder", i, SEED);
+            fund::<T>(currency_id, &who, amount);
+        }
+        Module::<T>::start_destroy_asset(RawOrigin::Root.into(), currency_id, DestroyPolicy::BurnAndRecord)?;
+    }: _(RawOrigin::Root, currency_id, c)
+    verify {
+        assert!(Module::<T>::asset_info_of(currency_id).is_none());
+    }
+
+    snapshot {
+        let currency_id = create_benchmark_asset::<T>();
+        // the oldest snapshot is pruned
+        let max = T::MaxSnapshots::get();
+        CurrentSnapshotId::<T>::insert(currency_id, max);
+        Snapshots::<T>::insert(currency_id, 1, frame_system::Module::<T>::block_number());
+        let origin = T::ManagerOrigin::successful_origin();
+        let call = Call::<T>::snapshot(currency_id);
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert_eq!(Module::<T>::current_snapshot_id(currency_id), max + 1);
+        assert_eq!(Module::<T>::snapshot_block(currency_id, 1), None);
+    }
+
+    unlock_expired {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let currency_id = create_benchmark_asset::<T>();
+        let amount: T::Balance = 1000u32.into();
+        fund::<T>(currency_id, &caller, amount);
+
+        let until = frame_system::Module::<T>::block_number() + One::one();
+        Module::<T>::set_lock_with_expiry(
+            BENCHMARK_LOCK_ID,
+            currency_id,
+            &caller,
+            amount,
+            until,
+            vec![b'R'; rio_protocol::LOCK_REASON_LEN],
+        )?;
+        frame_system::Module::<T>::set_block_number(until);
+    }: _(RawOrigin::Signed(caller.clone()), currency_id)
+    verify {
+        assert!(Module::<T>::locks(&caller, currency_id).is_empty());
+    }
+
+    register_chain {
+        let chain_id = ChainId::max_value();
+    }: _(RawOrigin::Root, chain_id, benchmark_chain_info())
+    verify {
+        assert_eq!(Module::<T>::chain_info(chain_id), Some(benchmark_chain_info()));
+    }
+
+    update_chain {
+        let chain_id = Chain::Bitcoin.id();
+    }: _(RawOrigin::Root, chain_id, benchmark_chain_info())
+    verify {
+        assert_eq!(Module::<T>::chain_info(chain_id), Some(benchmark_chain_info()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{ExtBuilder, TestRuntime};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn transfer() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_transfer::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn transfer_all() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_transfer_all::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn transfer_with_memo() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_transfer_with_memo::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn batch_transfer() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_batch_transfer::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn approve() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_approve::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn increase_allowance() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_increase_allowance::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn decrease_allowance() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_decrease_allowance::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn transfer_from() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_transfer_from::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn create() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_create::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn issue() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_issue::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn mint() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_mint::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn burn() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_burn::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn set_issuer() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_set_issuer::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn transfer_ownership() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_transfer_ownership::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn destroy() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_destroy::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn update_asset_info() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_update_asset_info::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn set_max_supply() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_set_max_supply::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn update_restriction() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_update_restriction::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn set_account_restrictions() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_set_account_restrictions::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn freeze_account() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_freeze_account::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn thaw_account() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_thaw_account::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn offline_asset() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_offline_asset::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn online_asset() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_online_asset::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn pause_asset() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_pause_asset::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn unpause_asset() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_unpause_asset::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn start_destroy_asset() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_start_destroy_asset::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn destroy_asset() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_destroy_asset::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn snapshot() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_snapshot::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn unlock_expired() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_unlock_expired::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn register_chain() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_register_chain::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn update_chain() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_update_chain::<TestRuntime>());
+        });
+    }
+}
@@ -0,0 +1,108 @@
+//! Invariants of the balances in rio-assets.
+//!
+//! They are checked by `Module::check_invariants`, which could be called from the tests or
+//! by the `AssetsApi` runtime api, it reads the whole `Accounts` and `Locks` so it must not be
+//! called in a block.
+
+use super::*;
+
+use frame_system::RefCount;
+
+/// A broken invariant found by `Module::check_invariants`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum Discrepancy<AccountId, CurrencyId, Balance> {
+    /// `TotalIssuance` is not the sum of free and reserved over `Accounts`.
+    /// [currency_id, total_issuance, sum_of_accounts]
+    TotalIssuance(CurrencyId, Balance, Balance),
+    /// The sum of free and reserved over `Accounts` overflows. [currency_id]
+    AccountsOverflow(CurrencyId),
+    /// The frozen balance of an account is not the max of its locks.
+    /// [currency_id, who, frozen, max_lock]
+    Frozen(CurrencyId, AccountId, Balance, Balance),
+    /// The ref count of an account is less than the refs taken by its entries in `Accounts`
+    /// and `Locks`, other modules may take more refs. [who, ref_count, taken]
+    RefCount(AccountId, RefCount, RefCount),
+}
+
+impl<T: Trait> Module<T> {
+    /// Check the invariants of the balances for all assets, returns the discrepancies found:
+    /// - `TotalIssuance[c] == Σ(free + reserved)` over `Accounts`
+    /// - `frozen == max(locks)` for every account
+    /// - the ref count of an account covers its entries in `Accounts` and `Locks`
+    pub fn check_invariants() -> Vec<Discrepancy<T::AccountId, T::CurrencyId, T::Balance>> {
+        let mut discrepancies = Vec::new();
+        // `None` if the sum overflows
+        let mut totals = BTreeMap::<T::CurrencyId, Option<T::Balance>>::new();
+        let mut refs = BTreeMap::<T::AccountId, RefCount>::new();
+
+        for (who, currency_id, account) in Accounts::<T>::iter() {
+            let total = totals
+                .entry(currency_id)
+                .or_insert_with(|| Some(Zero::zero()));
+            *total = total
+                .and_then(|t| t.checked_add(&account.free))
+                .and_then(|t| t.checked_add(&account.reserved));
+            *refs.entry(who.clone()).or_default() += 1;
+
+            let max_lock = Self::max_lock(&who, currency_id);
+            if account.frozen != max_lock {
+                discrepancies.push(Discrepancy::Frozen(
+                    currency_id,
+                    who,
+                    account.frozen,
+                    max_lock,
+                ));
+            }
+        }
+
+        for (who, currency_id, _) in Locks::<T>::iter() {
+            *refs.entry(who.clone()).or_default() += 1;
+
+            // the locked accounts are checked above
+            if !Accounts::<T>::contains_key(&who, currency_id) {
+                let max_lock = Self::max_lock(&who, currency_id);
+                if !max_lock.is_zero() {
+                    discrepancies.push(Discrepancy::Frozen(
+                        currency_id,
+                        who,
+                        Zero::zero(),
+                        max_lock,
+                    ));
+                }
+            }
+        }
+
+        for (currency_id, _) in TotalIssuance::<T>::iter() {
+            totals
+                .entry(currency_id)
+                .or_insert_with(|| Some(Zero::zero()));
+        }
+        for (currency_id, total) in totals {
+            let issuance = Self::total_issuance(currency_id);
+            match total {
+                Some(total) if total == issuance => {}
+                Some(total) => {
+                    discrepancies.push(Discrepancy::TotalIssuance(currency_id, issuance, total))
+                }
+                None => discrepancies.push(Discrepancy::AccountsOverflow(currency_id)),
+            }
+        }
+
+        for (who, taken) in refs {
+            let ref_count = frame_system::Module::<T>::account(&who).refcount;
+            if ref_count < taken {
+                discrepancies.push(Discrepancy::RefCount(who, ref_count, taken));
+            }
+        }
+
+        discrepancies
+    }
+
+    fn max_lock(who: &T::AccountId, currency_id: T::CurrencyId) -> T::Balance {
+        Self::locks(who, currency_id)
+            .iter()
+            .map(|lock| lock.amount)
+            .max()
+            .unwrap_or_else(Zero::zero)
+    }
+}
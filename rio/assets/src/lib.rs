@@ -1,5 +1,12 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+mod address;
+pub mod benchmarking;
+mod invariants;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
 pub mod types;
 mod weight_info;
 
@@ -7,10 +14,10 @@ use codec::{Decode, Encode};
 
 use sp_runtime::{
     traits::{
-        AtLeast32BitUnsigned, CheckedAdd, CheckedSub, MaybeSerializeDeserialize, Member,
-        Saturating, StaticLookup, Zero,
+        AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, CheckedSub,
+        MaybeSerializeDeserialize, Member, One, Saturating, StaticLookup, Zero,
     },
-    DispatchError, DispatchResult, RuntimeDebug,
+    DispatchError, DispatchResult, ModuleId, RuntimeDebug,
 };
 use sp_std::{
     collections::btree_map::BTreeMap,
@@ -20,12 +27,19 @@ use sp_std::{
 };
 
 use frame_support::{
-    decl_error, decl_event, decl_module, decl_storage, ensure, IterableStorageMap, Parameter,
-    StorageMap,
+    decl_error, decl_event, decl_module, decl_storage, ensure,
+    storage::migration::{put_storage_value, StorageIterator},
+    traits::{
+        BalanceStatus as NativeBalanceStatus, Currency, EnsureOrigin, Get, ReservableCurrency,
+    },
+    transactional,
+    weights::Weight,
+    IterableStorageDoubleMap, IterableStorageMap, Parameter, StorageMap,
 };
 use frame_system::{ensure_root, ensure_signed};
 
-use rio_support::info;
+use rio_primitives::{Memo, Text};
+use rio_support::{info, traits::AddressChecker};
 
 use orml_traits::{
     arithmetic::{self, Signed},
@@ -33,10 +47,18 @@ use orml_traits::{
     MultiReservableCurrency, OnReceived,
 };
 
-use crate::types::TotalAssetInfo;
-pub use types::{AssetInfo, Chain, Restriction, Restrictions};
+use crate::types::{AssetInfoV0, AssetInfoV1, Releases, TotalAssetInfo};
+pub use invariants::Discrepancy;
+pub use types::{
+    AddressFormat, AssetInfo, AssetOwnership, AssetPause, Chain, ChainId, ChainInfo, DestroyPolicy,
+    ForAccount, ForCurrency, ModuleAccount, OnDust, OnReceivedHandler, OnReceivedWeight, Pause,
+    PauseReason, ReceivedHandlers, ReserveReceived, Restriction, Restrictions, TransferDust,
+};
 pub use weight_info::WeightInfo;
 
+/// The id of a balance snapshot of an asset, starts from 1.
+pub type SnapshotId = u32;
+
 /// The module's configuration trait.
 pub trait Trait: frame_system::Trait {
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
@@ -63,22 +85,58 @@ pub trait Trait: frame_system::Trait {
     /// The currency ID type
     type CurrencyId: Parameter + Member + Copy + MaybeSerializeDeserialize + Ord;
 
-    /// Hook when some fund is deposited into an account
-    type OnReceived: OnReceived<Self::AccountId, Self::CurrencyId, Self::Balance>;
+    /// Hook when some fund is deposited into an account, its weight is charged by the calls
+    type OnReceived: OnReceived<Self::AccountId, Self::CurrencyId, Self::Balance> + OnReceivedWeight;
+
+    /// Handler for the dust burned when an account falls below the minimum balance
+    type OnDust: OnDust<Self::AccountId, Self::CurrencyId, Self::Balance>;
+
+    /// The native currency, used to reserve the deposit for user issued assets
+    type NativeCurrency: ReservableCurrency<Self::AccountId>;
+
+    /// The deposit reserved from the owner when issuing a user asset
+    type AssetDeposit: Get<NativeBalanceOf<Self>>;
+
+    /// The first currency id of user assets, the ids before it are kept for
+    /// system and cross-chain assets which could only be created by root
+    type UserAssetIdStart: Get<Self::CurrencyId>;
+
+    /// The origin which could freeze, thaw and restrict an account
+    type ManagerOrigin: EnsureOrigin<Self::Origin>;
+
+    /// The max holders or entries of an asset removed by a `destroy_asset` call
+    type MaxDestroyAccounts: Get<u32>;
+
+    /// The latest snapshots of an asset which could be queried, the older ones are pruned
+    type MaxSnapshots: Get<SnapshotId>;
 
     type WeightInfo: WeightInfo;
 }
 
+pub type NativeBalanceOf<T> =
+    <<T as Trait>::NativeCurrency as Currency<<T as frame_system::Trait>::AccountId>>::Balance;
+
 /// A single lock on a balance. There can be many of these on an account and
 /// they "overlap", so the same balance is frozen by multiple locks.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
-pub struct BalanceLock<Balance> {
+pub struct BalanceLock<Balance, BlockNumber> {
     /// An identifier for this lock. Only one lock may be in existence for each
     /// identifier.
     pub id: LockIdentifier,
     /// The amount which the free balance may not drop below when this lock is
     /// in effect.
     pub amount: Balance,
+    /// The lock expires at this block, `None` means it's kept until removed.
+    pub until: Option<BlockNumber>,
+    /// Why the balance is locked, human readable.
+    pub reason: Text,
+}
+
+/// `BalanceLock` before `Releases::V4`, only used in the migration.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct BalanceLockV0<Balance> {
+    pub id: LockIdentifier,
+    pub amount: Balance,
 }
 
 /// balance information for an account.
@@ -138,23 +196,134 @@ decl_error! {
         RestrictedAction,
         /// Symbol, Name or Desc too long
         TextTooLong,
+        /// currency id is not in the range of user assets, or the asset is not issued by user
+        NotUserAsset,
+        /// the caller is not the owner or the issuer of this asset
+        NoPermission,
+        /// asset still has issuance
+        AssetNotEmpty,
+        /// This operation will cause total issuance to exceed the max supply
+        ExceedMaxSupply,
+        /// The account is restricted by this action for this asset.
+        AccountRestricted,
+        /// The allowance is too low
+        AllowanceTooLow,
+        /// This operation will cause allowance to overflow
+        AllowanceOverflow,
+        /// Too many legs in a batch transfer
+        TooManyTransfers,
+        /// The memo is longer than `MEMO_BYTES_LEN`
+        MemoTooLong,
+        /// The balance of the receiver would be below the min balance of the asset
+        BelowMinBalance,
+        /// The asset id has been destroyed and can't be used again
+        AssetDestroyed,
+        /// The asset is being destroyed
+        AssetDestroying,
+        /// The asset is not being destroyed
+        AssetNotDestroying,
+        /// The snapshot id overflows
+        SnapshotIdOverflow,
+        /// The reason of a lock is longer than `LOCK_REASON_LEN`
+        LockReasonTooLong,
+        /// The expiry of a lock is not in the future
+        InvalidLockExpiry,
+        /// No lock of the account is expired
+        NoExpiredLock,
+        /// The chain id is already registered
+        ExistedChain,
+        /// The chain id is not registered
+        NotExistedChain,
+        /// The name of a chain is empty or longer than `CHAIN_NAME_LEN`
+        InvalidChainInfo,
+        /// The text of a pause reason is longer than `PAUSE_REASON_LEN`
+        PauseReasonTooLong,
+        /// The asset is paused, the state restored by `unpause_asset` could not be changed
+        AssetPaused,
+        /// The asset is not paused
+        AssetNotPaused,
     }
 }
 
 decl_event!(
     pub enum Event<T> where
         <T as frame_system::Trait>::AccountId,
+        <T as frame_system::Trait>::BlockNumber,
         <T as Trait>::CurrencyId,
-        <T as Trait>::Balance
+        <T as Trait>::Balance,
+        NativeBalance = NativeBalanceOf<T>,
+        AssetInfo = AssetInfo<<T as Trait>::Balance>,
     {
         /// Token transfer success. [currency_id, from, to, amount]
         Transferred(CurrencyId, AccountId, AccountId, Balance),
+        /// Token transfer with a memo success. [currency_id, from, to, amount, memo]
+        TransferredWithMemo(CurrencyId, AccountId, AccountId, Balance, Memo),
         /// Asset created (currency_id, creator, asset_options).
         Created(CurrencyId),
         /// update asset restrictions
         UpdateAssetRestriction(CurrencyId, Restrictions),
+        /// An asset is paused. [currency_id, pause, reason]
+        AssetPaused(CurrencyId, Pause, PauseReason),
+        /// The pause of an asset is reverted. [currency_id]
+        AssetUnpaused(CurrencyId),
+        /// The info of an asset is updated. [currency_id, old_info, new_info]
+        AssetInfoUpdated(CurrencyId, AssetInfo, AssetInfo),
+        /// A new chain is registered. [chain_id, chain_info]
+        ChainRegistered(ChainId, ChainInfo),
+        /// The info of a registered chain is updated. [chain_id, chain_info]
+        ChainUpdated(ChainId, ChainInfo),
         ///
         Revoke(CurrencyId),
+        /// User asset issued. [currency_id, owner, deposit]
+        Issued(CurrencyId, AccountId, NativeBalance),
+        /// Max supply of an asset changed, `None` means no cap. [currency_id, max_supply]
+        MaxSupplySet(CurrencyId, Option<Balance>),
+        /// update restrictions of an account for an asset. [currency_id, who, restrictions]
+        UpdateAccountRestriction(CurrencyId, AccountId, Restrictions),
+        /// An account is frozen for an asset. [currency_id, who]
+        AccountFrozen(CurrencyId, AccountId),
+        /// An account is thawed for an asset. [currency_id, who]
+        AccountThawed(CurrencyId, AccountId),
+        /// The allowance of a spender changed. [currency_id, owner, spender, allowance]
+        Approved(CurrencyId, AccountId, AccountId, Balance),
+        /// Token transferred by a spender from the owner. [currency_id, spender, from, to, amount]
+        TransferredFrom(CurrencyId, AccountId, AccountId, AccountId, Balance),
+        /// The issuer of a user asset changed. [currency_id, new_issuer]
+        IssuerChanged(CurrencyId, AccountId),
+        /// The owner of a user asset changed. [currency_id, new_owner]
+        OwnerChanged(CurrencyId, AccountId),
+        /// Asset destroyed, the id can't be used again. [currency_id]
+        Destroyed(CurrencyId),
+        /// Root starts to destroy an asset. [currency_id, policy]
+        DestroyStarted(CurrencyId, DestroyPolicy),
+        /// The balance and locks of a holder are wiped by destroying. [currency_id, who, amount]
+        AccountWiped(CurrencyId, AccountId, Balance),
+        /// A balance snapshot of an asset is taken. [currency_id, snapshot_id, block_number]
+        SnapshotTaken(CurrencyId, SnapshotId, BlockNumber),
+        /// Balance deposited into an account, the total issuance is increased.
+        /// [currency_id, who, amount]
+        Deposited(CurrencyId, AccountId, Balance),
+        /// Balance withdrawn from an account, the total issuance is decreased.
+        /// [currency_id, who, amount]
+        Withdrawn(CurrencyId, AccountId, Balance),
+        /// Balance slashed from an account, the total issuance is decreased.
+        /// [currency_id, who, free_amount, reserved_amount]
+        Slashed(CurrencyId, AccountId, Balance, Balance),
+        /// Balance moved from free to reserved. [currency_id, who, amount]
+        Reserved(CurrencyId, AccountId, Balance),
+        /// Balance moved from reserved to free. [currency_id, who, amount]
+        Unreserved(CurrencyId, AccountId, Balance),
+        /// Reserved balance moved to another account. [currency_id, from, to, amount, status]
+        ReserveRepatriated(CurrencyId, AccountId, AccountId, Balance, BalanceStatus),
+        /// A lock is set or extended. [lock_id, currency_id, who, amount]
+        LockSet(LockIdentifier, CurrencyId, AccountId, Balance),
+        /// A lock is removed. [lock_id, currency_id, who]
+        LockRemoved(LockIdentifier, CurrencyId, AccountId),
+        /// An account is reaped and its dust is burned. [currency_id, who, amount]
+        DustLost(CurrencyId, AccountId, Balance),
+        /// An account is reaped and its dust is moved to the dust account of `T::OnDust`.
+        /// [currency_id, who, dest, amount]
+        DustMoved(CurrencyId, AccountId, AccountId, Balance),
     }
 );
 
@@ -165,29 +334,118 @@ decl_storage! {
         pub AssetRestrictions get(fn asset_restrictions):
             map hasher(twox_64_concat) T::CurrencyId => Restrictions;
 
+        /// Restrictions means this account can't do something for this asset, it works
+        /// together with `AssetRestrictions`
+        pub AccountRestrictions get(fn account_restrictions):
+            double_map hasher(twox_64_concat) T::CurrencyId, hasher(blake2_128_concat) T::AccountId => Restrictions;
+
+        /// The accounts frozen by `freeze_account` for an asset, they are restricted by
+        /// `Restrictions::frozen()` in addition to `AccountRestrictions`.
+        pub FrozenAccounts get(fn is_frozen):
+            double_map hasher(twox_64_concat) T::CurrencyId, hasher(blake2_128_concat) T::AccountId => bool;
+
         /// "Symbols" can only keep Vec<u8>, and utf8 safety is totally on the client side
         pub AssetInfos get(fn asset_info_of):
-            map hasher(twox_64_concat) T::CurrencyId => Option<AssetInfo>;
+            map hasher(twox_64_concat) T::CurrencyId => Option<AssetInfo<T::Balance>>;
+
+        /// The infos of an asset since the creation, `(block, info)` means the asset is
+        /// described by `info` since `block`, ordered by the block number.
+        ///
+        /// NOTE: The history only grows by `create_asset` and `update_asset_info`, it's removed
+        /// when the asset is destroyed.
+        pub AssetInfoHistory get(fn asset_info_history):
+            map hasher(twox_64_concat) T::CurrencyId => Vec<(T::BlockNumber, AssetInfo<T::Balance>)>;
 
         pub Online get(fn online): map hasher(twox_64_concat) T::CurrencyId => bool;
 
+        /// The paused assets and the state to restore when they are unpaused.
+        pub PausedAssets get(fn paused_asset):
+            map hasher(twox_64_concat) T::CurrencyId => Option<AssetPause>;
+
+        /// The registry of the origin chains of the assets, the built-in chains of `Chain` are
+        /// registered in genesis.
+        pub Chains get(fn chain_info): map hasher(twox_64_concat) ChainId => Option<ChainInfo>;
+
+        /// The owner and issuer of assets issued by users
+        pub AssetOwnerships get(fn asset_ownership):
+            map hasher(twox_64_concat) T::CurrencyId => Option<AssetOwnership<T::AccountId, NativeBalanceOf<T>>>;
+
         /// The total issuance of a token type.
         pub TotalIssuance get(fn total_issuance): map hasher(twox_64_concat) T::CurrencyId => T::Balance;
 
+        /// The cap of total issuance for a token type, no cap if it's `None`.
+        pub MaxSupply get(fn max_supply): map hasher(twox_64_concat) T::CurrencyId => Option<T::Balance>;
+
+        /// The accumulated amount ever minted of a token type, including genesis endowments.
+        pub TotalMinted get(fn total_minted): map hasher(twox_64_concat) T::CurrencyId => T::Balance;
+
+        /// The accumulated amount ever burned of a token type.
+        ///
+        /// NOTE: `TotalMinted - TotalBurned` should always be equal to `TotalIssuance`.
+        pub TotalBurned get(fn total_burned): map hasher(twox_64_concat) T::CurrencyId => T::Balance;
+
         /// Any liquidity locks of a token type under an account.
         /// NOTE: Should only be accessed when setting, changing and freeing a lock.
-        pub Locks get(fn locks): double_map hasher(blake2_128_concat) T::AccountId, hasher(twox_64_concat) T::CurrencyId => Vec<BalanceLock<T::Balance>>;
+        pub Locks get(fn locks): double_map hasher(blake2_128_concat) T::AccountId, hasher(twox_64_concat) T::CurrencyId => Vec<BalanceLock<T::Balance, T::BlockNumber>>;
+
+        /// The amount which a spender is allowed to transfer from the owner, token type => (owner, spender).
+        pub Approvals get(fn approvals): double_map hasher(twox_64_concat) T::CurrencyId, hasher(blake2_128_concat) (T::AccountId, T::AccountId) => T::Balance;
 
         /// The balance of a token type under an account.
         ///
-        /// NOTE: If the total is ever zero, decrease account ref account.
+        /// NOTE: An entry holds a ref of the account, it's killed when the total falls below
+        /// the `min_balance` of the asset or to zero, unless some balance is frozen.
         ///
         /// NOTE: This is only used in the case that this module is used to store balances.
         pub Accounts get(fn accounts): double_map hasher(blake2_128_concat) T::AccountId, hasher(twox_64_concat) T::CurrencyId => AccountData<T::Balance>;
+
+        /// The holders of an asset, an entry exists as long as the `Accounts` entry exists.
+        pub AssetHolders get(fn asset_holders):
+            double_map hasher(twox_64_concat) T::CurrencyId, hasher(blake2_128_concat) T::AccountId => ();
+
+        /// The number of holders of an asset, i.e. the entries of the asset in `AssetHolders`.
+        pub HolderCount get(fn holder_count): map hasher(twox_64_concat) T::CurrencyId => u32;
+
+        /// The assets being destroyed by root and the policy for the balances of the holders.
+        pub DestroyingAssets get(fn destroying_asset):
+            map hasher(twox_64_concat) T::CurrencyId => Option<DestroyPolicy>;
+
+        /// The ids of destroyed assets, they can't be used again.
+        pub DestroyedAssets get(fn is_destroyed): map hasher(twox_64_concat) T::CurrencyId => bool;
+
+        /// The balances burned by destroying with `DestroyPolicy::BurnAndRecord`, to be settled
+        /// outside the chain.
+        pub DestroyedBalances get(fn destroyed_balance):
+            double_map hasher(twox_64_concat) T::CurrencyId, hasher(blake2_128_concat) T::AccountId => T::Balance;
+
+        /// The id of the latest balance snapshot of an asset, zero means no snapshot.
+        pub CurrentSnapshotId get(fn current_snapshot_id):
+            map hasher(twox_64_concat) T::CurrencyId => SnapshotId;
+
+        /// The block number at which a balance snapshot is taken.
+        pub Snapshots get(fn snapshot_block):
+            double_map hasher(twox_64_concat) T::CurrencyId, hasher(twox_64_concat) SnapshotId => Option<T::BlockNumber>;
+
+        /// The checkpoints of the total balance of an account, ordered by the snapshot id.
+        ///
+        /// A checkpoint `(id, balance)` is written lazily by the first balance change after
+        /// snapshot `id`, and `balance` is the balance before the change. Only the checkpoints
+        /// of the latest `MaxSnapshots` snapshots are kept.
+        pub BalanceCheckpoints get(fn balance_checkpoints):
+            double_map hasher(twox_64_concat) T::CurrencyId, hasher(blake2_128_concat) T::AccountId => Vec<(SnapshotId, T::Balance)>;
+
+        /// The locks expire at a block, `(who, currency_id, lock_id)`. An entry may be stale if
+        /// the lock is removed or replaced before.
+        pub LockExpiries get(fn lock_expiries):
+            map hasher(twox_64_concat) T::BlockNumber => Vec<(T::AccountId, T::CurrencyId, LockIdentifier)>;
+
+        /// Storage version of the module, used by the migrations.
+        StorageVersion build(|_: &GenesisConfig<T>| Releases::V6): Releases;
     }
     add_extra_genesis {
-        config(init): Vec<(T::CurrencyId, AssetInfo, Restrictions, Vec<(T::AccountId, T::Balance)>)>;
+        config(init): Vec<(T::CurrencyId, AssetInfo<T::Balance>, Restrictions, Vec<(T::AccountId, T::Balance)>)>;
         build(|config: &GenesisConfig<T>| {
+            <Module<T>>::register_builtin_chains();
             for (currency_id, info, restrictions, endowed) in config.init.iter() {
                 let currency_id = *currency_id;
                 // create asset
@@ -199,12 +457,14 @@ decl_storage! {
                 AssetRestrictions::<T>::insert(currency_id, restrictions);
                 // endowed
                 endowed.iter().for_each(|(account_id, initial_balance)| {
-                    <Accounts<T>>::mutate(account_id, currency_id, |account_data| account_data.free = *initial_balance)
+                    assert!(*initial_balance >= info.min_balance, "endowment below the min balance of the asset");
+                    <Module<T>>::set_free_balance(currency_id, account_id, *initial_balance);
                 });
                 let total = endowed.iter().map(|(_, b)| b).fold(Zero::zero(), |acc: T::Balance, initial_balance| {
                     acc + *initial_balance
                 });
                 TotalIssuance::<T>::insert(currency_id, total);
+                TotalMinted::<T>::insert(currency_id, total);
             }
         });
     }
@@ -216,6 +476,33 @@ decl_module! {
 
         fn deposit_event() = default;
 
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = 0;
+            if StorageVersion::get() == Releases::V0 {
+                weight += Self::migrate_to_v1();
+            }
+            if StorageVersion::get() == Releases::V1 {
+                weight += Self::migrate_to_v2();
+            }
+            if StorageVersion::get() == Releases::V2 {
+                weight += Self::migrate_to_v3();
+            }
+            if StorageVersion::get() == Releases::V3 {
+                weight += Self::migrate_to_v4();
+            }
+            if StorageVersion::get() == Releases::V4 {
+                weight += Self::migrate_to_v5();
+            }
+            if StorageVersion::get() == Releases::V5 {
+                weight += Self::migrate_to_v6();
+            }
+            weight
+        }
+
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            Self::expire_locks(now)
+        }
+
         /// Transfer some balance to another account.
         ///
         /// The dispatch origin for this call must be `Signed` by the transactor.
@@ -227,7 +514,8 @@ decl_module! {
         /// -------------------
         /// Base Weight: 26.65 µs
         /// # </weight>
-        #[weight = T::WeightInfo::transfer()]
+        #[weight = T::WeightInfo::transfer()
+            .saturating_add(T::OnReceived::on_received_weight())]
         pub fn transfer(
             origin,
             dest: <T::Lookup as StaticLookup>::Source,
@@ -252,7 +540,8 @@ decl_module! {
         /// -------------------
         /// Base Weight: 26.99 µs
         /// # </weight>
-        #[weight = T::WeightInfo::transfer_all()]
+        #[weight = T::WeightInfo::transfer_all()
+            .saturating_add(T::OnReceived::on_received_weight())]
         pub fn transfer_all(
             origin,
             dest: <T::Lookup as StaticLookup>::Source,
@@ -266,13 +555,152 @@ decl_module! {
             Self::deposit_event(RawEvent::Transferred(currency_id, from, to, balance));
         }
 
+        /// Transfer some balance to another account with a memo, so that the receiver
+        /// could attribute the transfer, e.g. a deposit to a shared hot wallet.
+        ///
+        /// The dispatch origin for this call must be `Signed` by the transactor.
+        ///
+        /// # <weight>
+        /// - Complexity: `O(1)`
+        /// - Db reads: 4
+        /// - Db writes: 2
+        /// # </weight>
+        #[weight = T::WeightInfo::transfer_with_memo()
+            .saturating_add(T::OnReceived::on_received_weight())]
+        pub fn transfer_with_memo(
+            origin,
+            dest: <T::Lookup as StaticLookup>::Source,
+            currency_id: T::CurrencyId,
+            #[compact] amount: T::Balance,
+            memo: Memo,
+        ) {
+            let from = ensure_signed(origin)?;
+            ensure!(memo.len() <= rio_protocol::MEMO_BYTES_LEN, Error::<T>::MemoTooLong);
+            let to = T::Lookup::lookup(dest)?;
+            <Self as MultiCurrency<_>>::transfer(currency_id, &from, &to, amount)?;
+
+            Self::deposit_event(RawEvent::TransferredWithMemo(currency_id, from, to, amount, memo));
+        }
+
+        /// Transfer some balances of different assets to different accounts. Either all
+        /// of the legs are applied or none of them is.
+        ///
+        /// The dispatch origin for this call must be `Signed` by the transactor.
+        ///
+        /// # <weight>
+        /// - Complexity: `O(C)` where `C` is the number of legs, at most `MAX_BATCH_TRANSFER`
+        /// - Db reads: 2 * C * `Accounts`
+        /// - Db writes: 2 * C * `Accounts`
+        /// # </weight>
+        #[weight = T::WeightInfo::batch_transfer(legs.len() as u32)
+            .saturating_add(T::OnReceived::on_received_weight().saturating_mul(legs.len() as Weight))]
+        pub fn batch_transfer(
+            origin,
+            legs: Vec<(<T::Lookup as StaticLookup>::Source, T::CurrencyId, T::Balance)>,
+        ) -> DispatchResult {
+            let from = ensure_signed(origin)?;
+            ensure!(legs.len() <= rio_protocol::MAX_BATCH_TRANSFER, Error::<T>::TooManyTransfers);
+
+            Self::do_batch_transfer(&from, legs)
+        }
+
+        /// Set the amount which `spender` is allowed to transfer from the caller, it
+        /// replaces the previous allowance.
+        ///
+        /// The dispatch origin for this call must be `Signed` by the owner.
+        #[weight = T::WeightInfo::approve()]
+        pub fn approve(
+            origin,
+            spender: <T::Lookup as StaticLookup>::Source,
+            currency_id: T::CurrencyId,
+            #[compact] amount: T::Balance,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            let spender = T::Lookup::lookup(spender)?;
+            let _ = Self::get_asset(&currency_id)?;
+
+            Self::set_allowance(currency_id, &owner, &spender, amount);
+            Ok(())
+        }
+
+        /// Increase the allowance of `spender` by `amount`.
+        ///
+        /// The dispatch origin for this call must be `Signed` by the owner.
+        #[weight = T::WeightInfo::increase_allowance()]
+        pub fn increase_allowance(
+            origin,
+            spender: <T::Lookup as StaticLookup>::Source,
+            currency_id: T::CurrencyId,
+            #[compact] amount: T::Balance,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            let spender = T::Lookup::lookup(spender)?;
+            let _ = Self::get_asset(&currency_id)?;
+
+            let allowance = Self::allowance(currency_id, &owner, &spender)
+                .checked_add(&amount)
+                .ok_or(Error::<T>::AllowanceOverflow)?;
+            Self::set_allowance(currency_id, &owner, &spender, allowance);
+            Ok(())
+        }
+
+        /// Decrease the allowance of `spender` by `amount`.
+        ///
+        /// The dispatch origin for this call must be `Signed` by the owner.
+        #[weight = T::WeightInfo::decrease_allowance()]
+        pub fn decrease_allowance(
+            origin,
+            spender: <T::Lookup as StaticLookup>::Source,
+            currency_id: T::CurrencyId,
+            #[compact] amount: T::Balance,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            let spender = T::Lookup::lookup(spender)?;
+
+            let allowance = Self::allowance(currency_id, &owner, &spender)
+                .checked_sub(&amount)
+                .ok_or(Error::<T>::AllowanceTooLow)?;
+            Self::set_allowance(currency_id, &owner, &spender, allowance);
+            Ok(())
+        }
+
+        /// Transfer some balance of `from` to another account, using the allowance
+        /// approved by `from` to the caller.
+        ///
+        /// The dispatch origin for this call must be `Signed` by the spender.
+        #[weight = T::WeightInfo::transfer_from()
+            .saturating_add(T::OnReceived::on_received_weight())]
+        pub fn transfer_from(
+            origin,
+            from: <T::Lookup as StaticLookup>::Source,
+            dest: <T::Lookup as StaticLookup>::Source,
+            currency_id: T::CurrencyId,
+            #[compact] amount: T::Balance,
+        ) -> DispatchResult {
+            let spender = ensure_signed(origin)?;
+            let from = T::Lookup::lookup(from)?;
+            let to = T::Lookup::lookup(dest)?;
+
+            // the spender must not be restricted either
+            Self::can_do(&currency_id, &spender, Restriction::Transferable)?;
+            let allowance = Self::allowance(currency_id, &from, &spender)
+                .checked_sub(&amount)
+                .ok_or(Error::<T>::AllowanceTooLow)?;
+
+            <Self as MultiCurrency<_>>::transfer(currency_id, &from, &to, amount)?;
+            Self::set_allowance(currency_id, &from, &spender, allowance);
+
+            Self::deposit_event(RawEvent::TransferredFrom(currency_id, spender, from, to, amount));
+            Ok(())
+        }
+
         /// create a new asset with full permissions granted to whoever make the call
         /// *sudo or proposal approved only*
         #[weight = T::WeightInfo::create()]
         pub fn create(
             origin,
             currency_id: T::CurrencyId,
-            asset_info: AssetInfo,
+            asset_info: AssetInfo<T::Balance>,
         ) -> DispatchResult {
             ensure_root(origin)?;
 
@@ -281,29 +709,274 @@ decl_module! {
             Ok(())
         }
 
+        /// Issue a new user asset, the caller would be the owner and the issuer of it.
+        ///
+        /// `currency_id` must not be less than `UserAssetIdStart`, and `AssetDeposit` of
+        /// the native currency is reserved from the caller until the asset is destroyed.
+        #[weight = T::WeightInfo::issue()]
+        #[transactional]
+        pub fn issue(
+            origin,
+            currency_id: T::CurrencyId,
+            asset_info: AssetInfo<T::Balance>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(currency_id >= T::UserAssetIdStart::get(), Error::<T>::NotUserAsset);
+            ensure!(asset_info.chain == Chain::Rio.id(), Error::<T>::InvalidAssetInfo);
+            ensure!(Self::asset_info_of(&currency_id).is_none(), Error::<T>::ExistedAsset);
+
+            // the asset info is checked by `create_asset`, the deposit is unreserved if it fails
+            let deposit = T::AssetDeposit::get();
+            T::NativeCurrency::reserve(&who, deposit)?;
+
+            Self::create_asset(currency_id, asset_info)?;
+            AssetOwnerships::<T>::insert(currency_id, AssetOwnership {
+                owner: who.clone(),
+                issuer: who.clone(),
+                deposit,
+            });
+
+            Self::deposit_event(RawEvent::Issued(currency_id, who, deposit));
+            Ok(())
+        }
+
+        /// Mint some user asset to an account.
+        ///
+        /// The dispatch origin for this call must be `Signed` by the issuer of the asset.
+        #[weight = T::WeightInfo::mint()
+            .saturating_add(T::OnReceived::on_received_weight())]
+        pub fn mint(
+            origin,
+            currency_id: T::CurrencyId,
+            dest: <T::Lookup as StaticLookup>::Source,
+            #[compact] amount: T::Balance,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let dest = T::Lookup::lookup(dest)?;
+            let ownership = Self::asset_ownership(currency_id).ok_or(Error::<T>::NotUserAsset)?;
+            ensure!(ownership.issuer == who, Error::<T>::NoPermission);
+
+            <Self as MultiCurrency<_>>::deposit(currency_id, &dest, amount)?;
+            Ok(())
+        }
+
+        /// Burn some user asset from the free balance of the issuer.
+        ///
+        /// The dispatch origin for this call must be `Signed` by the issuer of the asset.
+        #[weight = T::WeightInfo::burn()]
+        pub fn burn(
+            origin,
+            currency_id: T::CurrencyId,
+            #[compact] amount: T::Balance,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let ownership = Self::asset_ownership(currency_id).ok_or(Error::<T>::NotUserAsset)?;
+            ensure!(ownership.issuer == who, Error::<T>::NoPermission);
+
+            <Self as MultiCurrency<_>>::withdraw(currency_id, &who, amount)?;
+            Ok(())
+        }
+
+        /// Change the issuer of a user asset.
+        ///
+        /// The dispatch origin for this call must be `Signed` by the owner of the asset.
+        #[weight = T::WeightInfo::set_issuer()]
+        pub fn set_issuer(
+            origin,
+            currency_id: T::CurrencyId,
+            issuer: <T::Lookup as StaticLookup>::Source,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let issuer = T::Lookup::lookup(issuer)?;
+
+            AssetOwnerships::<T>::try_mutate(currency_id, |maybe_ownership| -> DispatchResult {
+                let ownership = maybe_ownership.as_mut().ok_or(Error::<T>::NotUserAsset)?;
+                ensure!(ownership.owner == who, Error::<T>::NoPermission);
+                ownership.issuer = issuer.clone();
+                Ok(())
+            })?;
+
+            Self::deposit_event(RawEvent::IssuerChanged(currency_id, issuer));
+            Ok(())
+        }
+
+        /// Transfer the ownership of a user asset, the deposit is moved to the new owner.
+        ///
+        /// The dispatch origin for this call must be `Signed` by the owner of the asset.
+        #[weight = T::WeightInfo::transfer_ownership()]
+        pub fn transfer_ownership(
+            origin,
+            currency_id: T::CurrencyId,
+            owner: <T::Lookup as StaticLookup>::Source,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let owner = T::Lookup::lookup(owner)?;
+
+            AssetOwnerships::<T>::try_mutate(currency_id, |maybe_ownership| -> DispatchResult {
+                let ownership = maybe_ownership.as_mut().ok_or(Error::<T>::NotUserAsset)?;
+                ensure!(ownership.owner == who, Error::<T>::NoPermission);
+                if ownership.owner == owner {
+                    return Ok(());
+                }
+                T::NativeCurrency::repatriate_reserved(
+                    &ownership.owner,
+                    &owner,
+                    ownership.deposit,
+                    NativeBalanceStatus::Reserved,
+                )?;
+                ownership.owner = owner.clone();
+                Ok(())
+            })?;
+
+            Self::deposit_event(RawEvent::OwnerChanged(currency_id, owner));
+            Ok(())
+        }
+
+        /// Destroy a user asset and return the deposit to the owner.
+        ///
+        /// The restrictions, approvals, checkpoints and snapshots of the asset are removed at
+        /// most `max_entries` at a time, capped by `MaxDestroyAccounts`. If some are left, the
+        /// asset is put into destroying and the owner calls again to remove the rest.
+        ///
+        /// The dispatch origin for this call must be `Signed` by the owner of the asset,
+        /// and all issuance of the asset must be burned before.
+        #[weight = T::WeightInfo::destroy((*max_entries).min(T::MaxDestroyAccounts::get()))]
+        pub fn destroy(origin, currency_id: T::CurrencyId, max_entries: u32) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let ownership = Self::asset_ownership(currency_id).ok_or(Error::<T>::NotUserAsset)?;
+            ensure!(ownership.owner == who, Error::<T>::NoPermission);
+            ensure!(Self::total_issuance(currency_id).is_zero(), Error::<T>::AssetNotEmpty);
+            ensure!(Self::holder_count(currency_id).is_zero(), Error::<T>::AssetNotEmpty);
+
+            let max_entries = max_entries.min(T::MaxDestroyAccounts::get()) as usize;
+            if Self::clear_asset_entries(currency_id, max_entries) {
+                Self::remove_asset(currency_id);
+            } else if Self::destroying_asset(currency_id).is_none() {
+                // no holder is left, so nothing is burned by the policy
+                Online::<T>::remove(currency_id);
+                DestroyingAssets::<T>::insert(currency_id, DestroyPolicy::Burn);
+                Self::deposit_event(RawEvent::DestroyStarted(currency_id, DestroyPolicy::Burn));
+            }
+            Ok(())
+        }
+
+        /// Update the info of an asset, it's checked like the info of a new asset.
+        ///
+        /// The old info is kept in `AssetInfoHistory`.
         #[weight = T::WeightInfo::update_asset_info()]
         pub fn update_asset_info(
             origin,
             currency_id: T::CurrencyId,
-            asset_info: AssetInfo,
+            asset_info: AssetInfo<T::Balance>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let old = Self::asset_info_of(currency_id).ok_or(Error::<T>::NotExistedAsset)?;
+            Self::check_asset_info(&asset_info)?;
+
+            AssetInfos::<T>::insert(currency_id, &asset_info);
+            Self::record_asset_info(currency_id, &asset_info);
+
+            Self::deposit_event(RawEvent::AssetInfoUpdated(currency_id, old, asset_info));
+            Ok(())
+        }
+
+        /// Set the cap of total issuance for an asset, `None` to remove the cap.
+        ///
+        /// The new cap could not be less than the current total issuance.
+        #[weight = T::WeightInfo::set_max_supply()]
+        pub fn set_max_supply(
+            origin,
+            currency_id: T::CurrencyId,
+            max_supply: Option<T::Balance>,
         ) -> DispatchResult {
             ensure_root(origin)?;
             ensure!(Self::asset_info_of(currency_id).is_some(), Error::<T>::NotExistedAsset);
-            AssetInfos::<T>::insert(currency_id, asset_info);
+            match max_supply {
+                Some(max) => {
+                    ensure!(Self::total_issuance(currency_id) <= max, Error::<T>::ExceedMaxSupply);
+                    MaxSupply::<T>::insert(currency_id, max);
+                }
+                None => MaxSupply::<T>::remove(currency_id),
+            }
+            Self::deposit_event(RawEvent::MaxSupplySet(currency_id, max_supply));
             Ok(())
         }
 
         #[weight = T::WeightInfo::update_restriction()]
         pub fn update_restriction(origin, currency_id: T::CurrencyId, restrictions: Restrictions) {
             ensure_root(origin)?;
+            ensure!(Self::paused_asset(currency_id).is_none(), Error::<T>::AssetPaused);
             <AssetRestrictions<T>>::insert(currency_id, restrictions);
             Self::deposit_event(RawEvent::UpdateAssetRestriction(currency_id, restrictions));
         }
 
+        /// Set the restrictions of an account for an asset, `Restrictions::none()` to clear it.
+        ///
+        /// The dispatch origin for this call must be `ManagerOrigin`.
+        #[weight = T::WeightInfo::set_account_restrictions()]
+        pub fn set_account_restrictions(
+            origin,
+            who: <T::Lookup as StaticLookup>::Source,
+            currency_id: T::CurrencyId,
+            restrictions: Restrictions,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            let who = T::Lookup::lookup(who)?;
+            ensure!(Self::asset_info_of(currency_id).is_some(), Error::<T>::NotExistedAsset);
+
+            Self::set_account_restrictions_of(currency_id, &who, restrictions);
+            Self::deposit_event(RawEvent::UpdateAccountRestriction(currency_id, who, restrictions));
+            Ok(())
+        }
+
+        /// Freeze an account for an asset, the account could not transfer, withdraw or
+        /// reserve this asset until thawed.
+        ///
+        /// The dispatch origin for this call must be `ManagerOrigin`.
+        #[weight = T::WeightInfo::freeze_account()]
+        pub fn freeze_account(
+            origin,
+            who: <T::Lookup as StaticLookup>::Source,
+            currency_id: T::CurrencyId,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            let who = T::Lookup::lookup(who)?;
+            ensure!(Self::asset_info_of(currency_id).is_some(), Error::<T>::NotExistedAsset);
+
+            FrozenAccounts::<T>::insert(currency_id, &who, true);
+            Self::deposit_event(RawEvent::AccountFrozen(currency_id, who));
+            Ok(())
+        }
+
+        /// Thaw an account for an asset, only the freeze of `freeze_account` is lifted, the
+        /// restrictions set by `set_account_restrictions` are kept even if they overlap.
+        ///
+        /// The dispatch origin for this call must be `ManagerOrigin`.
+        #[weight = T::WeightInfo::thaw_account()]
+        pub fn thaw_account(
+            origin,
+            who: <T::Lookup as StaticLookup>::Source,
+            currency_id: T::CurrencyId,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            let who = T::Lookup::lookup(who)?;
+
+            FrozenAccounts::<T>::remove(currency_id, &who);
+            Self::deposit_event(RawEvent::AccountThawed(currency_id, who));
+            Ok(())
+        }
+
         #[weight = T::WeightInfo::offline_asset()]
         pub fn offline_asset(origin, currency_id: T::CurrencyId) {
             ensure_root(origin)?;
             Online::<T>::remove(currency_id);
+            // kept offline by `unpause_asset`
+            PausedAssets::<T>::mutate(currency_id, |paused| {
+                if let Some(paused) = paused {
+                    paused.was_online = false;
+                }
+            });
             Self::deposit_event(RawEvent::Revoke(currency_id));
         }
 
@@ -311,10 +984,208 @@ decl_module! {
         pub fn online_asset(origin, currency_id: T::CurrencyId) -> DispatchResult {
             ensure_root(origin)?;
             ensure!(Self::asset_info_of(currency_id).is_some(), Error::<T>::NotExistedAsset);
+            ensure!(Self::destroying_asset(currency_id).is_none(), Error::<T>::AssetDestroying);
+            ensure!(Self::paused_asset(currency_id).is_none(), Error::<T>::AssetPaused);
             Online::<T>::insert(currency_id, true);
             Self::deposit_event(RawEvent::Created(currency_id));
             Ok(())
         }
+
+        /// Pause an asset for a reason until `unpause_asset`, e.g. for a planned maintenance of
+        /// the origin chain, the asset is put offline or restricted as `pause`.
+        ///
+        /// The dispatch origin for this call must be `Root`.
+        #[weight = T::WeightInfo::pause_asset()]
+        pub fn pause_asset(
+            origin,
+            currency_id: T::CurrencyId,
+            pause: Pause,
+            reason: PauseReason,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                reason.text.len() <= rio_protocol::PAUSE_REASON_LEN,
+                Error::<T>::PauseReasonTooLong
+            );
+            ensure!(Self::asset_info_of(currency_id).is_some(), Error::<T>::NotExistedAsset);
+            ensure!(Self::destroying_asset(currency_id).is_none(), Error::<T>::AssetDestroying);
+            ensure!(Self::paused_asset(currency_id).is_none(), Error::<T>::AssetPaused);
+
+            PausedAssets::<T>::insert(currency_id, AssetPause {
+                pause,
+                reason: reason.clone(),
+                was_online: Self::online(currency_id),
+                restrictions: Self::asset_restrictions(currency_id),
+            });
+            match pause {
+                Pause::Offline => Online::<T>::remove(currency_id),
+                Pause::Restrict(restrictions) => {
+                    AssetRestrictions::<T>::insert(currency_id, restrictions)
+                }
+            }
+            Self::deposit_event(RawEvent::AssetPaused(currency_id, pause, reason));
+            Ok(())
+        }
+
+        /// Revert the pause of an asset, the online state or the restrictions before the pause
+        /// are restored. An asset being destroyed stays offline.
+        ///
+        /// The dispatch origin for this call must be `Root`.
+        #[weight = T::WeightInfo::unpause_asset()]
+        pub fn unpause_asset(origin, currency_id: T::CurrencyId) -> DispatchResult {
+            ensure_root(origin)?;
+            let paused = PausedAssets::<T>::take(currency_id).ok_or(Error::<T>::AssetNotPaused)?;
+
+            match paused.pause {
+                Pause::Offline => {
+                    if paused.was_online && Self::destroying_asset(currency_id).is_none() {
+                        Online::<T>::insert(currency_id, true);
+                    }
+                }
+                Pause::Restrict(_) => {
+                    AssetRestrictions::<T>::insert(currency_id, paused.restrictions)
+                }
+            }
+            Self::deposit_event(RawEvent::AssetUnpaused(currency_id));
+            Ok(())
+        }
+
+        /// Start to destroy an asset, the asset is put offline at once and `destroy_asset`
+        /// should be called until the asset is removed.
+        ///
+        /// The dispatch origin for this call must be `Root`.
+        #[weight = T::WeightInfo::start_destroy_asset()]
+        pub fn start_destroy_asset(
+            origin,
+            currency_id: T::CurrencyId,
+            policy: DestroyPolicy,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(Self::asset_info_of(currency_id).is_some(), Error::<T>::NotExistedAsset);
+            ensure!(Self::destroying_asset(currency_id).is_none(), Error::<T>::AssetDestroying);
+
+            Online::<T>::remove(currency_id);
+            DestroyingAssets::<T>::insert(currency_id, policy);
+            Self::deposit_event(RawEvent::DestroyStarted(currency_id, policy));
+            Ok(())
+        }
+
+        /// Wipe the balances and locks of at most `max_accounts` holders of an asset being
+        /// destroyed. When no holder is left, the restrictions, approvals, checkpoints and
+        /// snapshots of the asset are removed in the same way, and then the asset is removed.
+        ///
+        /// `max_accounts` is capped by `MaxDestroyAccounts`.
+        ///
+        /// The dispatch origin for this call must be `Root`.
+        ///
+        /// # <weight>
+        /// - Complexity: `O(C)` where `C` is `max_accounts`
+        /// - Db reads: C * (`AssetHolders` + `Accounts` + `Locks`)
+        /// - Db writes: C * (`AssetHolders` + `Accounts` + `Locks` + `frame_system::Account`)
+        /// # </weight>
+        #[weight = T::WeightInfo::destroy_asset((*max_accounts).min(T::MaxDestroyAccounts::get()))]
+        pub fn destroy_asset(
+            origin,
+            currency_id: T::CurrencyId,
+            max_accounts: u32,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let policy = Self::destroying_asset(currency_id).ok_or(Error::<T>::AssetNotDestroying)?;
+            let max_accounts = max_accounts.min(T::MaxDestroyAccounts::get()) as usize;
+
+            let holders = AssetHolders::<T>::iter_prefix(currency_id)
+                .map(|(who, _)| who)
+                .take(max_accounts + 1)
+                .collect::<Vec<_>>();
+            if holders.len() > max_accounts {
+                for who in holders.iter().take(max_accounts) {
+                    Self::wipe_account(currency_id, who, policy);
+                }
+                return Ok(());
+            }
+            for who in holders.iter() {
+                Self::wipe_account(currency_id, who, policy);
+            }
+
+            if Self::clear_asset_entries(currency_id, max_accounts - holders.len()) {
+                Self::remove_asset(currency_id);
+            }
+            Ok(())
+        }
+
+        /// Take a balance snapshot of an asset at the current block, the balances at the
+        /// snapshot could be queried by `balance_at` later. Only the latest `MaxSnapshots`
+        /// snapshots are kept, the oldest one is pruned.
+        ///
+        /// The dispatch origin for this call must be `ManagerOrigin`, or `Signed` by the owner
+        /// of a user asset.
+        #[weight = T::WeightInfo::snapshot()]
+        pub fn snapshot(origin, currency_id: T::CurrencyId) -> DispatchResult {
+            if let Err(origin) = T::ManagerOrigin::try_origin(origin) {
+                let who = ensure_signed(origin)?;
+                let ownership = Self::asset_ownership(currency_id).ok_or(Error::<T>::NoPermission)?;
+                ensure!(ownership.owner == who, Error::<T>::NoPermission);
+            }
+            ensure!(Self::asset_info_of(currency_id).is_some(), Error::<T>::NotExistedAsset);
+
+            let snapshot_id = Self::current_snapshot_id(currency_id)
+                .checked_add(1)
+                .ok_or(Error::<T>::SnapshotIdOverflow)?;
+            let now = frame_system::Module::<T>::block_number();
+            CurrentSnapshotId::<T>::insert(currency_id, snapshot_id);
+            Snapshots::<T>::insert(currency_id, snapshot_id, now);
+            if let Some(pruned) = snapshot_id.checked_sub(T::MaxSnapshots::get()) {
+                Snapshots::<T>::remove(currency_id, pruned);
+            }
+
+            Self::deposit_event(RawEvent::SnapshotTaken(currency_id, snapshot_id, now));
+            Ok(())
+        }
+
+        /// Remove the expired locks of the caller for an asset, without waiting for the sweep
+        /// in `on_initialize`.
+        ///
+        /// The dispatch origin for this call must be `Signed` by the holder.
+        #[weight = T::WeightInfo::unlock_expired()]
+        pub fn unlock_expired(origin, currency_id: T::CurrencyId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let now = frame_system::Module::<T>::block_number();
+
+            let expired = Self::locks(&who, currency_id)
+                .into_iter()
+                .filter(|lock| lock.until.map_or(false, |until| until <= now))
+                .map(|lock| lock.id)
+                .collect::<Vec<_>>();
+            ensure!(!expired.is_empty(), Error::<T>::NoExpiredLock);
+            for lock_id in expired {
+                <Self as MultiLockableCurrency<_>>::remove_lock(lock_id, currency_id, &who);
+            }
+            Ok(())
+        }
+
+        /// Register a new origin chain for the assets.
+        #[weight = T::WeightInfo::register_chain()]
+        pub fn register_chain(origin, chain_id: ChainId, chain_info: ChainInfo) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(!Chains::contains_key(chain_id), Error::<T>::ExistedChain);
+            Self::check_chain_info(&chain_info)?;
+
+            Chains::insert(chain_id, &chain_info);
+            Self::deposit_event(RawEvent::ChainRegistered(chain_id, chain_info));
+            Ok(())
+        }
+
+        /// Update the info of a registered chain, e.g. the required confirmations.
+        #[weight = T::WeightInfo::update_chain()]
+        pub fn update_chain(origin, chain_id: ChainId, chain_info: ChainInfo) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(Chains::contains_key(chain_id), Error::<T>::NotExistedChain);
+            Self::check_chain_info(&chain_info)?;
+
+            Chains::insert(chain_id, &chain_info);
+            Self::deposit_event(RawEvent::ChainUpdated(chain_id, chain_info));
+            Ok(())
+        }
     }
 }
 
@@ -323,7 +1194,9 @@ impl<T: Trait> Module<T> {
 
     /// check the currency_id is existed
     #[inline]
-    pub fn get_asset(currency_id: &T::CurrencyId) -> result::Result<AssetInfo, DispatchError> {
+    pub fn get_asset(
+        currency_id: &T::CurrencyId,
+    ) -> result::Result<AssetInfo<T::Balance>, DispatchError> {
         if let Some(asset) = Self::asset_info_of(currency_id) {
             if Self::online(currency_id) {
                 Ok(asset)
@@ -340,13 +1213,34 @@ impl<T: Trait> Module<T> {
     /// # Arguments
     /// * `currency_id`: An ID of a reserved asset.
     ///
-    pub fn create_asset(currency_id: T::CurrencyId, asset_info: AssetInfo) -> DispatchResult {
+    pub fn create_asset(
+        currency_id: T::CurrencyId,
+        asset_info: AssetInfo<T::Balance>,
+    ) -> DispatchResult {
         // make sure the asset id is not exist
         ensure!(
             Self::asset_info_of(&currency_id).is_none(),
             Error::<T>::ExistedAsset
         );
+        ensure!(!Self::is_destroyed(currency_id), Error::<T>::AssetDestroyed);
+        Self::check_asset_info(&asset_info)?;
+
+        info!(
+            "[create_asset]|currency_id:{:?}, symbol:{:?}",
+            currency_id, asset_info.symbol
+        );
+
+        Self::record_asset_info(currency_id, &asset_info);
+        <AssetInfos<T>>::insert(currency_id, asset_info);
+        Online::<T>::insert(currency_id, true);
+
+        Self::deposit_event(RawEvent::Created(currency_id));
+
+        Ok(())
+    }
 
+    /// check the length of texts and the decimals of an asset info
+    fn check_asset_info(asset_info: &AssetInfo<T::Balance>) -> DispatchResult {
         ensure!(
             asset_info.symbol.len() <= rio_protocol::ASSET_SYMBOL_LEN,
             Error::<T>::InvalidAssetInfo
@@ -360,17 +1254,146 @@ impl<T: Trait> Module<T> {
             Error::<T>::InvalidAssetInfo
         );
         ensure!(asset_info.decimals != 0, Error::<T>::InvalidAssetInfo);
+        ensure!(
+            Chains::contains_key(asset_info.chain),
+            Error::<T>::NotExistedChain
+        );
+        Ok(())
+    }
 
-        info!(
-            "[create_asset]|currency_id:{:?}, symbol:{:?}",
-            currency_id, asset_info.symbol
+    /// check the name of a chain
+    fn check_chain_info(chain_info: &ChainInfo) -> DispatchResult {
+        ensure!(
+            !chain_info.name.is_empty() && chain_info.name.len() <= rio_protocol::CHAIN_NAME_LEN,
+            Error::<T>::InvalidChainInfo
         );
+        Ok(())
+    }
 
-        <AssetInfos<T>>::insert(currency_id, asset_info);
-        Online::<T>::insert(currency_id, true);
+    /// Register the chains of `Chain` with their ids, an existing entry is kept.
+    fn register_builtin_chains() -> Weight {
+        let mut reads_writes: Weight = 0;
+        for chain in Chain::iterator() {
+            reads_writes += 1;
+            if !Chains::contains_key(chain.id()) {
+                Chains::insert(chain.id(), chain.info());
+            }
+        }
+        T::DbWeight::get().reads_writes(reads_writes, reads_writes)
+    }
 
-        Self::deposit_event(RawEvent::Created(currency_id));
+    /// Append the info of an asset to `AssetInfoHistory`, it replaces the last one if both
+    /// are written in the current block.
+    fn record_asset_info(currency_id: T::CurrencyId, asset_info: &AssetInfo<T::Balance>) {
+        let now = frame_system::Module::<T>::block_number();
+        AssetInfoHistory::<T>::mutate(currency_id, |history| match history.last_mut() {
+            Some((block, info)) if *block == now => *info = asset_info.clone(),
+            _ => history.push((now, asset_info.clone())),
+        });
+    }
 
+    /// The info of an asset at `block`, `None` if the asset is not created yet.
+    pub fn asset_info_at(
+        currency_id: T::CurrencyId,
+        block: T::BlockNumber,
+    ) -> Option<AssetInfo<T::Balance>> {
+        let history = Self::asset_info_history(currency_id);
+        let index = match history.binary_search_by(|(b, _)| b.cmp(&block)) {
+            Ok(index) => index,
+            Err(0) => return None,
+            Err(index) => index - 1,
+        };
+        history.into_iter().nth(index).map(|(_, info)| info)
+    }
+
+    /// The amount which `spender` is allowed to transfer from `owner`.
+    pub fn allowance(
+        currency_id: T::CurrencyId,
+        owner: &T::AccountId,
+        spender: &T::AccountId,
+    ) -> T::Balance {
+        Self::approvals(currency_id, (owner.clone(), spender.clone()))
+    }
+
+    /// The total balance of `who` at a snapshot, `None` if the snapshot is not taken.
+    pub fn balance_at(
+        currency_id: T::CurrencyId,
+        who: &T::AccountId,
+        snapshot_id: SnapshotId,
+    ) -> Option<T::Balance> {
+        if snapshot_id < Self::oldest_snapshot_id(currency_id)
+            || snapshot_id > Self::current_snapshot_id(currency_id)
+        {
+            return None;
+        }
+
+        // the first checkpoint since the snapshot keeps the balance at the snapshot, the
+        // balance is not changed since the snapshot if there is no such checkpoint
+        let checkpoints = Self::balance_checkpoints(currency_id, who);
+        let index = match checkpoints.binary_search_by_key(&snapshot_id, |(id, _)| *id) {
+            Ok(index) | Err(index) => index,
+        };
+        Some(
+            checkpoints
+                .get(index)
+                .map(|(_, balance)| *balance)
+                .unwrap_or_else(|| Self::total_balance(currency_id, who)),
+        )
+    }
+
+    /// The holders of an asset and their total balances, at most `limit` of them are
+    /// returned after skipping `offset`.
+    pub fn holders(
+        currency_id: T::CurrencyId,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<(T::AccountId, T::Balance)> {
+        AssetHolders::<T>::iter_prefix(currency_id)
+            .skip(offset as usize)
+            .take(limit as usize)
+            .map(|(who, _)| {
+                let balance = Self::total_balance(currency_id, &who);
+                (who, balance)
+            })
+            .collect()
+    }
+
+    /// Set a lock on the balance of `who` which expires at `until`, the lock is removed by
+    /// the sweep in `on_initialize` or by `unlock_expired`. It replaces the lock with the
+    /// same id like `set_lock`.
+    pub fn set_lock_with_expiry(
+        lock_id: LockIdentifier,
+        currency_id: T::CurrencyId,
+        who: &T::AccountId,
+        amount: T::Balance,
+        until: T::BlockNumber,
+        reason: Text,
+    ) -> DispatchResult {
+        ensure!(
+            reason.len() <= rio_protocol::LOCK_REASON_LEN,
+            Error::<T>::LockReasonTooLong
+        );
+        ensure!(
+            until > frame_system::Module::<T>::block_number(),
+            Error::<T>::InvalidLockExpiry
+        );
+        if amount.is_zero() {
+            return Ok(());
+        }
+
+        let mut locks = Self::locks(who, currency_id);
+        locks.retain(|lock| lock.id != lock_id);
+        locks.push(BalanceLock {
+            id: lock_id,
+            amount,
+            until: Some(until),
+            reason,
+        });
+        Self::update_locks(currency_id, who, &locks[..]);
+        Self::deposit_lock_set(lock_id, currency_id, who, &locks[..]);
+        LockExpiries::<T>::mutate(until, |expiries| {
+            expiries.push((who.clone(), currency_id, lock_id))
+        });
         Ok(())
     }
 
@@ -384,19 +1407,158 @@ impl<T: Trait> Module<T> {
                         balance: Self::total_issuance(id),
                         is_online: Self::online(id),
                         restrictions: Self::asset_restrictions(id),
+                        holders: Self::holder_count(id),
                     },
                 )
             })
             .collect()
     }
+
+    /// The balances of all the assets held by the accounts of `module_ids`, see `ModuleAccount`.
+    pub fn module_account_balances(
+        module_ids: &[ModuleId],
+    ) -> Vec<(
+        ModuleId,
+        T::AccountId,
+        Vec<(T::CurrencyId, AccountData<T::Balance>)>,
+    )> {
+        module_ids
+            .iter()
+            .map(|module_id| {
+                let who: T::AccountId = module_id.into_account();
+                let balances = Accounts::<T>::iter_prefix(&who).collect();
+                (*module_id, who, balances)
+            })
+            .collect()
+    }
 }
 
 impl<T: Trait> Module<T> {
+    /// The minimum balance of an asset, zero if the asset doesn't exist.
+    pub fn min_balance(currency_id: T::CurrencyId) -> T::Balance {
+        Self::asset_info_of(currency_id)
+            .map(|info| info.min_balance)
+            .unwrap_or_else(Zero::zero)
+    }
+
+    /// Ensure `who` would hold at least the minimum balance after receiving `amount`.
+    fn ensure_min_balance(
+        currency_id: T::CurrencyId,
+        who: &T::AccountId,
+        amount: T::Balance,
+    ) -> DispatchResult {
+        ensure!(
+            Self::total_balance(currency_id, who).saturating_add(amount)
+                >= Self::min_balance(currency_id),
+            Error::<T>::BelowMinBalance
+        );
+        Ok(())
+    }
+
+    /// Mutate the account data of `who` under `currency_id`.
+    ///
+    /// A new entry takes a ref of the account. The entry is killed and the ref is released
+    /// when nothing is frozen and the total falls below the `min_balance` of the asset or to
+    /// zero, the dust is handed to `T::OnDust` and what it doesn't take is burned.
+    ///
+    /// Note this will not maintain total issuance except for the burned dust.
+    fn mutate_account(
+        currency_id: T::CurrencyId,
+        who: &T::AccountId,
+        f: impl FnOnce(&mut AccountData<T::Balance>),
+    ) {
+        let existed = <Accounts<T>>::contains_key(who, currency_id);
+        let mut account = Self::accounts(who, currency_id);
+        Self::update_checkpoint(currency_id, who, account.total());
+        f(&mut account);
+
+        let total = account.total();
+        let reap =
+            account.frozen.is_zero() && (total.is_zero() || total < Self::min_balance(currency_id));
+        if !reap {
+            <Accounts<T>>::insert(who, currency_id, account);
+            if !existed {
+                Self::add_holder(currency_id, who);
+                frame_system::Module::<T>::inc_ref(who);
+            }
+            return;
+        }
+
+        <Accounts<T>>::remove(who, currency_id);
+        if existed {
+            Self::remove_holder(currency_id, who);
+            frame_system::Module::<T>::dec_ref(who);
+        }
+        if !total.is_zero() {
+            let lost = T::OnDust::on_dust(who, currency_id, total);
+            if !lost.is_zero() {
+                <TotalIssuance<T>>::mutate(currency_id, |v| *v = v.saturating_sub(lost));
+                Self::on_burned(currency_id, lost);
+                Self::deposit_event(RawEvent::DustLost(currency_id, who.clone(), lost));
+            }
+        }
+    }
+
+    /// Credit the dust of the reaped `who` to `dest`, it's moved like a transfer so the
+    /// issuance is kept and no restriction of `dest` is checked. Returns the dust if `dest`
+    /// would be below the `min_balance` with it, then nothing is moved.
+    fn move_dust(
+        currency_id: T::CurrencyId,
+        who: &T::AccountId,
+        dest: &T::AccountId,
+        amount: T::Balance,
+    ) -> T::Balance {
+        let total = Self::accounts(dest, currency_id)
+            .total()
+            .saturating_add(amount);
+        if who == dest || total < Self::min_balance(currency_id) {
+            return amount;
+        }
+        Self::mutate_account(currency_id, dest, |account_data| {
+            account_data.free = account_data.free.saturating_add(amount)
+        });
+        Self::deposit_event(RawEvent::DustMoved(
+            currency_id,
+            who.clone(),
+            dest.clone(),
+            amount,
+        ));
+        Zero::zero()
+    }
+
+    /// Write the balance before a change as the checkpoint of the latest snapshot, if it's the
+    /// first change since the snapshot.
+    fn update_checkpoint(currency_id: T::CurrencyId, who: &T::AccountId, balance: T::Balance) {
+        let current = Self::current_snapshot_id(currency_id);
+        if current.is_zero() {
+            return;
+        }
+        let mut checkpoints = Self::balance_checkpoints(currency_id, who);
+        if checkpoints.last().map_or(true, |(id, _)| *id < current) {
+            // the checkpoints of the pruned snapshots are never read again
+            let oldest = Self::oldest_snapshot_id(currency_id);
+            let pruned = checkpoints
+                .iter()
+                .take_while(|(id, _)| *id < oldest)
+                .count();
+            checkpoints.drain(..pruned);
+            checkpoints.push((current, balance));
+            BalanceCheckpoints::<T>::insert(currency_id, who, checkpoints);
+        }
+    }
+
+    /// The id of the oldest snapshot kept of an asset.
+    fn oldest_snapshot_id(currency_id: T::CurrencyId) -> SnapshotId {
+        Self::current_snapshot_id(currency_id)
+            .saturating_sub(T::MaxSnapshots::get())
+            .saturating_add(1)
+    }
+
     /// Set free balance of `who` to a new value.
     ///
     /// Note this will not maintain total issuance.
     fn set_free_balance(currency_id: T::CurrencyId, who: &T::AccountId, balance: T::Balance) {
-        <Accounts<T>>::mutate(who, currency_id, |account_data| account_data.free = balance);
+        Self::mutate_account(currency_id, who, |account_data| account_data.free = balance);
     }
 
     /// Set reserved balance of `who` to a new value, meanwhile enforce
@@ -405,19 +1567,364 @@ impl<T: Trait> Module<T> {
     /// Note this will not maintain total issuance, and the caller is expected
     /// to do it.
     fn set_reserved_balance(currency_id: T::CurrencyId, who: &T::AccountId, balance: T::Balance) {
-        <Accounts<T>>::mutate(who, currency_id, |account_data| {
+        Self::mutate_account(currency_id, who, |account_data| {
             account_data.reserved = balance
         });
     }
 
+    /// Upgrade `AssetInfos` with the `min_balance`, and take the refs of the existing balance
+    /// entries, the entries with nothing are killed.
+    fn migrate_to_v1() -> Weight {
+        let mut reads_writes: Weight = 0;
+        // `AssetInfos` is in the format of `AssetInfoV1` until `migrate_to_v6`
+        for (hash, info) in StorageIterator::<AssetInfoV0>::new(b"RioAssets", b"AssetInfos") {
+            reads_writes += 1;
+            put_storage_value(
+                b"RioAssets",
+                b"AssetInfos",
+                &hash,
+                info.upgrade::<T::Balance>(),
+            );
+        }
+
+        for (who, currency_id, account) in <Accounts<T>>::iter().collect::<Vec<_>>() {
+            reads_writes += 1;
+            if account.total().is_zero() && account.frozen.is_zero() {
+                <Accounts<T>>::remove(&who, currency_id);
+            } else {
+                frame_system::Module::<T>::inc_ref(&who);
+            }
+        }
+
+        StorageVersion::put(Releases::V1);
+        T::DbWeight::get().reads_writes(reads_writes + 1, reads_writes + 1)
+    }
+
+    /// Index the holders of the existing balance entries.
+    fn migrate_to_v2() -> Weight {
+        let mut reads_writes: Weight = 0;
+        for (who, currency_id, _) in <Accounts<T>>::iter() {
+            reads_writes += 1;
+            AssetHolders::<T>::insert(currency_id, who, ());
+        }
+
+        StorageVersion::put(Releases::V2);
+        T::DbWeight::get().reads_writes(reads_writes + 1, reads_writes + 1)
+    }
+
+    /// Count the holders indexed by `migrate_to_v2`.
+    fn migrate_to_v3() -> Weight {
+        let mut reads_writes: Weight = 0;
+        for (currency_id, _, _) in AssetHolders::<T>::iter() {
+            reads_writes += 1;
+            HolderCount::<T>::mutate(currency_id, |count| *count = count.saturating_add(1));
+        }
+
+        StorageVersion::put(Releases::V3);
+        T::DbWeight::get().reads_writes(reads_writes + 1, reads_writes + 1)
+    }
+
+    /// Upgrade `Locks` with the expiry and the reason.
+    fn migrate_to_v4() -> Weight {
+        let mut reads_writes: Weight = 0;
+        <Locks<T>>::translate::<Vec<BalanceLockV0<T::Balance>>, _>(|_, _, locks| {
+            reads_writes += 1;
+            Some(
+                locks
+                    .into_iter()
+                    .map(|lock| BalanceLock {
+                        id: lock.id,
+                        amount: lock.amount,
+                        until: None,
+                        reason: Vec::new(),
+                    })
+                    .collect(),
+            )
+        });
+
+        StorageVersion::put(Releases::V4);
+        T::DbWeight::get().reads_writes(reads_writes + 1, reads_writes + 1)
+    }
+
+    /// Start `AssetInfoHistory` with the current infos, the creation block of the existing
+    /// assets is unknown so they are recorded since the genesis.
+    fn migrate_to_v5() -> Weight {
+        let mut reads_writes: Weight = 0;
+        // both are in the format of `AssetInfoV1` until `migrate_to_v6`, with the same hasher
+        for (hash, info) in
+            StorageIterator::<AssetInfoV1<T::Balance>>::new(b"RioAssets", b"AssetInfos")
+        {
+            reads_writes += 1;
+            put_storage_value(
+                b"RioAssets",
+                b"AssetInfoHistory",
+                &hash,
+                sp_std::vec![(T::BlockNumber::zero(), info)],
+            );
+        }
+
+        StorageVersion::put(Releases::V5);
+        T::DbWeight::get().reads_writes(reads_writes + 1, reads_writes + 1)
+    }
+
+    /// Register the built-in chains, and replace the `Chain` in `AssetInfos` and
+    /// `AssetInfoHistory` with the chain id.
+    fn migrate_to_v6() -> Weight {
+        let mut reads_writes: Weight = 0;
+        AssetInfos::<T>::translate::<AssetInfoV1<T::Balance>, _>(|_, info| {
+            reads_writes += 1;
+            Some(info.upgrade())
+        });
+        AssetInfoHistory::<T>::translate::<Vec<(T::BlockNumber, AssetInfoV1<T::Balance>)>, _>(
+            |_, history| {
+                reads_writes += 1;
+                Some(
+                    history
+                        .into_iter()
+                        .map(|(block, info)| (block, info.upgrade()))
+                        .collect(),
+                )
+            },
+        );
+
+        StorageVersion::put(Releases::V6);
+        Self::register_builtin_chains()
+            .saturating_add(T::DbWeight::get().reads_writes(reads_writes + 1, reads_writes + 1))
+    }
+
+    /// Remove the locks expiring at `now`, at most `MAX_LOCK_EXPIRIES_PER_BLOCK` of them,
+    /// the rest are moved to the next block.
+    fn expire_locks(now: T::BlockNumber) -> Weight {
+        let mut expiries = LockExpiries::<T>::take(now);
+        if expiries.is_empty() {
+            return T::DbWeight::get().reads(1);
+        }
+        if expiries.len() > rio_protocol::MAX_LOCK_EXPIRIES_PER_BLOCK {
+            let rest = expiries.split_off(rio_protocol::MAX_LOCK_EXPIRIES_PER_BLOCK);
+            LockExpiries::<T>::mutate(now + One::one(), |next| next.extend(rest));
+        }
+
+        let count = expiries.len() as Weight;
+        for (who, currency_id, lock_id) in expiries {
+            let expired = Self::locks(&who, currency_id)
+                .iter()
+                .any(|lock| lock.id == lock_id && lock.until.map_or(false, |until| until <= now));
+            if expired {
+                <Self as MultiLockableCurrency<_>>::remove_lock(lock_id, currency_id, &who);
+            }
+        }
+        T::DbWeight::get().reads_writes(2 + 3 * count, 2 + 4 * count)
+    }
+
+    fn add_holder(currency_id: T::CurrencyId, who: &T::AccountId) {
+        AssetHolders::<T>::insert(currency_id, who, ());
+        HolderCount::<T>::mutate(currency_id, |count| *count = count.saturating_add(1));
+    }
+
+    fn remove_holder(currency_id: T::CurrencyId, who: &T::AccountId) {
+        AssetHolders::<T>::remove(currency_id, who);
+        HolderCount::<T>::mutate(currency_id, |count| *count = count.saturating_sub(1));
+    }
+
+    /// Remove the balance entry and locks of `who`, the balance is burned.
+    fn wipe_account(currency_id: T::CurrencyId, who: &T::AccountId, policy: DestroyPolicy) {
+        let total = Self::accounts(who, currency_id).total();
+        <Accounts<T>>::remove(who, currency_id);
+        Self::remove_holder(currency_id, who);
+        frame_system::Module::<T>::dec_ref(who);
+        if <Locks<T>>::contains_key(who, currency_id) {
+            <Locks<T>>::remove(who, currency_id);
+            frame_system::Module::<T>::dec_ref(who);
+        }
+
+        if !total.is_zero() {
+            <TotalIssuance<T>>::mutate(currency_id, |v| *v = v.saturating_sub(total));
+            Self::on_burned(currency_id, total);
+            if policy == DestroyPolicy::BurnAndRecord {
+                DestroyedBalances::<T>::mutate(currency_id, who, |v| *v = v.saturating_add(total));
+            }
+        }
+        Self::deposit_event(RawEvent::AccountWiped(currency_id, who.clone(), total));
+    }
+
+    /// Remove at most `limit` entries of an asset keyed by the accounts or the snapshots,
+    /// returns whether all of them are removed.
+    fn clear_asset_entries(currency_id: T::CurrencyId, limit: usize) -> bool {
+        let mut left = limit;
+
+        let restricted = AccountRestrictions::<T>::iter_prefix(currency_id)
+            .map(|(who, _)| who)
+            .take(left)
+            .collect::<Vec<_>>();
+        left -= restricted.len();
+        for who in restricted {
+            AccountRestrictions::<T>::remove(currency_id, who);
+        }
+
+        let frozen = FrozenAccounts::<T>::iter_prefix(currency_id)
+            .map(|(who, _)| who)
+            .take(left)
+            .collect::<Vec<_>>();
+        left -= frozen.len();
+        for who in frozen {
+            FrozenAccounts::<T>::remove(currency_id, who);
+        }
+
+        let approvals = Approvals::<T>::iter_prefix(currency_id)
+            .map(|(key, _)| key)
+            .take(left)
+            .collect::<Vec<_>>();
+        left -= approvals.len();
+        for key in approvals {
+            Approvals::<T>::remove(currency_id, key);
+        }
+
+        let checkpoints = BalanceCheckpoints::<T>::iter_prefix(currency_id)
+            .map(|(who, _)| who)
+            .take(left)
+            .collect::<Vec<_>>();
+        left -= checkpoints.len();
+        for who in checkpoints {
+            BalanceCheckpoints::<T>::remove(currency_id, who);
+        }
+
+        let snapshots = Snapshots::<T>::iter_prefix(currency_id)
+            .map(|(id, _)| id)
+            .take(left)
+            .collect::<Vec<_>>();
+        for id in snapshots {
+            Snapshots::<T>::remove(currency_id, id);
+        }
+
+        AccountRestrictions::<T>::iter_prefix(currency_id)
+            .next()
+            .is_none()
+            && FrozenAccounts::<T>::iter_prefix(currency_id).next().is_none()
+            && Approvals::<T>::iter_prefix(currency_id).next().is_none()
+            && BalanceCheckpoints::<T>::iter_prefix(currency_id)
+                .next()
+                .is_none()
+            && Snapshots::<T>::iter_prefix(currency_id).next().is_none()
+    }
+
+    /// Remove everything of an asset except the accounting of the supply, and mark the id
+    /// as destroyed. The deposit of a user asset is returned to the owner.
+    ///
+    /// NOTE: The entries of the asset in `LockExpiries` are stale since the locks are wiped,
+    /// they are dropped when they expire.
+    fn remove_asset(currency_id: T::CurrencyId) {
+        AssetInfos::<T>::remove(currency_id);
+        AssetInfoHistory::<T>::remove(currency_id);
+        CurrentSnapshotId::<T>::remove(currency_id);
+        Online::<T>::remove(currency_id);
+        AssetRestrictions::<T>::remove(currency_id);
+        PausedAssets::<T>::remove(currency_id);
+        TotalIssuance::<T>::remove(currency_id);
+        MaxSupply::<T>::remove(currency_id);
+        DestroyingAssets::<T>::remove(currency_id);
+        HolderCount::<T>::remove(currency_id);
+        if let Some(ownership) = AssetOwnerships::<T>::take(currency_id) {
+            T::NativeCurrency::unreserve(&ownership.owner, ownership.deposit);
+        }
+        DestroyedAssets::<T>::insert(currency_id, true);
+
+        Self::deposit_event(RawEvent::Destroyed(currency_id));
+    }
+
+    /// Apply all legs of a batch transfer, the whole batch is reverted if any leg fails.
+    #[transactional]
+    fn do_batch_transfer(
+        from: &T::AccountId,
+        legs: Vec<(
+            <T::Lookup as StaticLookup>::Source,
+            T::CurrencyId,
+            T::Balance,
+        )>,
+    ) -> DispatchResult {
+        for (dest, currency_id, amount) in legs {
+            let to = T::Lookup::lookup(dest)?;
+            <Self as MultiCurrency<_>>::transfer(currency_id, from, &to, amount)?;
+            Self::deposit_event(RawEvent::Transferred(currency_id, from.clone(), to, amount));
+        }
+        Ok(())
+    }
+
+    /// Set the allowance of `spender` from `owner`, remove the entry if it's zero.
+    fn set_allowance(
+        currency_id: T::CurrencyId,
+        owner: &T::AccountId,
+        spender: &T::AccountId,
+        amount: T::Balance,
+    ) {
+        if amount.is_zero() {
+            Approvals::<T>::remove(currency_id, (owner.clone(), spender.clone()));
+        } else {
+            Approvals::<T>::insert(currency_id, (owner.clone(), spender.clone()), amount);
+        }
+        Self::deposit_event(RawEvent::Approved(
+            currency_id,
+            owner.clone(),
+            spender.clone(),
+            amount,
+        ));
+    }
+
+    /// Set the restrictions of `who` under `currency_id`, remove the entry if it's none.
+    fn set_account_restrictions_of(
+        currency_id: T::CurrencyId,
+        who: &T::AccountId,
+        restrictions: Restrictions,
+    ) {
+        if restrictions.is_none() {
+            AccountRestrictions::<T>::remove(currency_id, who);
+        } else {
+            AccountRestrictions::<T>::insert(currency_id, who, restrictions);
+        }
+    }
+
+    /// Record `amount` of `currency_id` newly issued, the event is emitted by the caller with
+    /// the balance movement.
+    fn on_minted(currency_id: T::CurrencyId, amount: T::Balance) {
+        if amount.is_zero() {
+            return;
+        }
+        TotalMinted::<T>::mutate(currency_id, |v| *v = v.saturating_add(amount));
+    }
+
+    /// Record `amount` of `currency_id` removed from the issuance, the event is emitted by the
+    /// caller with the balance movement.
+    fn on_burned(currency_id: T::CurrencyId, amount: T::Balance) {
+        if amount.is_zero() {
+            return;
+        }
+        TotalBurned::<T>::mutate(currency_id, |v| *v = v.saturating_add(amount));
+    }
+
+    /// Emit `LockSet` with the resulting amount of `lock_id` in `locks`.
+    fn deposit_lock_set(
+        lock_id: LockIdentifier,
+        currency_id: T::CurrencyId,
+        who: &T::AccountId,
+        locks: &[BalanceLock<T::Balance, T::BlockNumber>],
+    ) {
+        if let Some(lock) = locks.iter().find(|lock| lock.id == lock_id) {
+            Self::deposit_event(RawEvent::LockSet(
+                lock_id,
+                currency_id,
+                who.clone(),
+                lock.amount,
+            ));
+        }
+    }
+
     /// Update the account entry for `who` under `currency_id`, given the locks.
     fn update_locks(
         currency_id: T::CurrencyId,
         who: &T::AccountId,
-        locks: &[BalanceLock<T::Balance>],
+        locks: &[BalanceLock<T::Balance, T::BlockNumber>],
     ) {
         // update account data
-        <Accounts<T>>::mutate(who, currency_id, |account_data| {
+        Self::mutate_account(currency_id, who, |account_data| {
             account_data.frozen = Zero::zero();
             for lock in locks.iter() {
                 account_data.frozen = account_data.frozen.max(lock.amount);
@@ -467,7 +1974,7 @@ impl<T: Trait> MultiCurrency<T::AccountId> for Module<T> {
         amount: Self::Balance,
     ) -> DispatchResult {
         let _ = Self::get_asset(&currency_id)?;
-        Self::can_do(&currency_id, Restriction::Withdrawable)?;
+        Self::can_do(&currency_id, who, Restriction::Withdrawable)?;
 
         if amount.is_zero() {
             return Ok(());
@@ -493,17 +2000,20 @@ impl<T: Trait> MultiCurrency<T::AccountId> for Module<T> {
         amount: Self::Balance,
     ) -> DispatchResult {
         let _ = Self::get_asset(&currency_id)?;
-        Self::can_do(&currency_id, Restriction::Transferable)?;
+        Self::can_do(&currency_id, from, Restriction::Transferable)?;
 
         if amount.is_zero() || from == to {
             return Ok(());
         }
 
         Self::ensure_can_withdraw(currency_id, from, amount)?;
+        Self::ensure_min_balance(currency_id, to, amount)?;
 
         let from_balance = Self::free_balance(currency_id, from);
-        let to_balance = Self::free_balance(currency_id, to);
         Self::set_free_balance(currency_id, from, from_balance - amount);
+        // read after the `from` side, the dust of `from` is moved to `to` by `T::OnDust` if
+        // `to` is the dust account
+        let to_balance = Self::free_balance(currency_id, to);
         Self::set_free_balance(currency_id, to, to_balance + amount);
         T::OnReceived::on_received(to, currency_id, amount);
 
@@ -519,7 +2029,7 @@ impl<T: Trait> MultiCurrency<T::AccountId> for Module<T> {
         amount: Self::Balance,
     ) -> DispatchResult {
         let _ = Self::get_asset(&currency_id)?;
-        Self::can_do(&currency_id, Restriction::Depositable)?;
+        Self::can_do(&currency_id, who, Restriction::Depositable)?;
 
         if amount.is_zero() {
             return Ok(());
@@ -528,13 +2038,19 @@ impl<T: Trait> MultiCurrency<T::AccountId> for Module<T> {
         let new_total = Self::total_issuance(currency_id)
             .checked_add(&amount)
             .ok_or(Error::<T>::TotalIssuanceOverflow)?;
+        if let Some(max) = Self::max_supply(currency_id) {
+            ensure!(new_total <= max, Error::<T>::ExceedMaxSupply);
+        }
+        Self::ensure_min_balance(currency_id, who, amount)?;
         <TotalIssuance<T>>::insert(currency_id, new_total);
         Self::set_free_balance(
             currency_id,
             who,
             Self::free_balance(currency_id, who) + amount,
         );
+        Self::on_minted(currency_id, amount);
         T::OnReceived::on_received(who, currency_id, amount);
+        Self::deposit_event(RawEvent::Deposited(currency_id, who.clone(), amount));
 
         Ok(())
     }
@@ -545,7 +2061,7 @@ impl<T: Trait> MultiCurrency<T::AccountId> for Module<T> {
         amount: Self::Balance,
     ) -> DispatchResult {
         let _ = Self::get_asset(&currency_id)?;
-        Self::can_do(&currency_id, Restriction::Withdrawable)?;
+        Self::can_do(&currency_id, who, Restriction::Withdrawable)?;
 
         if amount.is_zero() {
             return Ok(());
@@ -558,6 +2074,8 @@ impl<T: Trait> MultiCurrency<T::AccountId> for Module<T> {
             who,
             Self::free_balance(currency_id, who) - amount,
         );
+        Self::on_burned(currency_id, amount);
+        Self::deposit_event(RawEvent::Withdrawn(currency_id, who.clone(), amount));
 
         Ok(())
     }
@@ -565,7 +2083,7 @@ impl<T: Trait> MultiCurrency<T::AccountId> for Module<T> {
     // Check if `value` amount of free balance can be slashed from `who`.
     fn can_slash(currency_id: Self::CurrencyId, who: &T::AccountId, value: Self::Balance) -> bool {
         if Self::get_asset(&currency_id).is_err()
-            || Self::can_do(&currency_id, Restriction::Slashable).is_err()
+            || Self::can_do(&currency_id, who, Restriction::Slashable).is_err()
         {
             return false;
         }
@@ -596,23 +2114,28 @@ impl<T: Trait> MultiCurrency<T::AccountId> for Module<T> {
         let free_slashed_amount = account.free.min(amount);
         let mut remaining_slash = amount - free_slashed_amount;
 
-        // slash free balance
-        if !free_slashed_amount.is_zero() {
-            Self::set_free_balance(currency_id, who, account.free - free_slashed_amount);
-        }
-
         // slash reserved balance
-        if !remaining_slash.is_zero() {
-            let reserved_slashed_amount = account.reserved.min(remaining_slash);
-            remaining_slash -= reserved_slashed_amount;
-            Self::set_reserved_balance(
-                currency_id,
-                who,
-                account.reserved - reserved_slashed_amount,
-            );
+        let reserved_slashed_amount = account.reserved.min(remaining_slash);
+        remaining_slash -= reserved_slashed_amount;
+
+        // both parts are applied at once, so the account is reaped by the final total
+        if !free_slashed_amount.is_zero() || !reserved_slashed_amount.is_zero() {
+            Self::mutate_account(currency_id, who, |account_data| {
+                account_data.free -= free_slashed_amount;
+                account_data.reserved -= reserved_slashed_amount;
+            });
         }
 
         <TotalIssuance<T>>::mutate(currency_id, |v| *v -= amount - remaining_slash);
+        Self::on_burned(currency_id, amount - remaining_slash);
+        if remaining_slash != amount {
+            Self::deposit_event(RawEvent::Slashed(
+                currency_id,
+                who.clone(),
+                free_slashed_amount,
+                reserved_slashed_amount,
+            ));
+        }
         remaining_slash
     }
 }
@@ -620,6 +2143,7 @@ impl<T: Trait> MultiCurrency<T::AccountId> for Module<T> {
 impl<T: Trait> MultiCurrencyExtended<T::AccountId> for Module<T> {
     type Amount = T::Amount;
 
+    /// Positive `by_amount` goes through `deposit`, so it is also limited by `MaxSupply`.
     fn update_balance(
         currency_id: Self::CurrencyId,
         who: &T::AccountId,
@@ -656,6 +2180,8 @@ impl<T: Trait> MultiLockableCurrency<T::AccountId> for Module<T> {
         let mut new_lock = Some(BalanceLock {
             id: lock_id,
             amount,
+            until: None,
+            reason: Vec::new(),
         });
         let mut locks = Self::locks(who, currency_id)
             .into_iter()
@@ -671,6 +2197,7 @@ impl<T: Trait> MultiLockableCurrency<T::AccountId> for Module<T> {
             locks.push(lock)
         }
         Self::update_locks(currency_id, who, &locks[..]);
+        Self::deposit_lock_set(lock_id, currency_id, who, &locks[..]);
     }
 
     // Extend a lock on the balance of `who` under `currency_id`.
@@ -687,14 +2214,16 @@ impl<T: Trait> MultiLockableCurrency<T::AccountId> for Module<T> {
         let mut new_lock = Some(BalanceLock {
             id: lock_id,
             amount,
+            until: None,
+            reason: Vec::new(),
         });
         let mut locks = Self::locks(who, currency_id)
             .into_iter()
             .filter_map(|lock| {
                 if lock.id == lock_id {
                     new_lock.take().map(|nl| BalanceLock {
-                        id: lock.id,
                         amount: lock.amount.max(nl.amount),
+                        ..lock
                     })
                 } else {
                     Some(lock)
@@ -705,12 +2234,18 @@ impl<T: Trait> MultiLockableCurrency<T::AccountId> for Module<T> {
             locks.push(lock)
         }
         Self::update_locks(currency_id, who, &locks[..]);
+        Self::deposit_lock_set(lock_id, currency_id, who, &locks[..]);
     }
 
     fn remove_lock(lock_id: LockIdentifier, currency_id: Self::CurrencyId, who: &T::AccountId) {
         let mut locks = Self::locks(who, currency_id);
+        let len = locks.len();
         locks.retain(|lock| lock.id != lock_id);
+        if locks.len() == len {
+            return;
+        }
         Self::update_locks(currency_id, who, &locks[..]);
+        Self::deposit_event(RawEvent::LockRemoved(lock_id, currency_id, who.clone()));
     }
 }
 
@@ -724,7 +2259,7 @@ impl<T: Trait> MultiReservableCurrency<T::AccountId> for Module<T> {
         value: Self::Balance,
     ) -> bool {
         if Self::get_asset(&currency_id).is_err()
-            || Self::can_do(&currency_id, Restriction::Reservable).is_err()
+            || Self::can_do(&currency_id, who, Restriction::Reservable).is_err()
         {
             return false;
         }
@@ -752,6 +2287,15 @@ impl<T: Trait> MultiReservableCurrency<T::AccountId> for Module<T> {
         let actual = reserved_balance.min(value);
         Self::set_reserved_balance(currency_id, who, reserved_balance - actual);
         <TotalIssuance<T>>::mutate(currency_id, |v| *v -= actual);
+        Self::on_burned(currency_id, actual);
+        if !actual.is_zero() {
+            Self::deposit_event(RawEvent::Slashed(
+                currency_id,
+                who.clone(),
+                Zero::zero(),
+                actual,
+            ));
+        }
         value - actual
     }
 
@@ -768,16 +2312,18 @@ impl<T: Trait> MultiReservableCurrency<T::AccountId> for Module<T> {
         value: Self::Balance,
     ) -> DispatchResult {
         let _ = Self::get_asset(&currency_id)?;
-        Self::can_do(&currency_id, Restriction::Reservable)?;
+        Self::can_do(&currency_id, who, Restriction::Reservable)?;
 
         if value.is_zero() {
             return Ok(());
         }
         Self::ensure_can_withdraw(currency_id, who, value)?;
 
-        let account = Self::accounts(who, currency_id);
-        Self::set_free_balance(currency_id, who, account.free - value);
-        Self::set_reserved_balance(currency_id, who, account.reserved + value);
+        Self::mutate_account(currency_id, who, |account_data| {
+            account_data.free -= value;
+            account_data.reserved += value;
+        });
+        Self::deposit_event(RawEvent::Reserved(currency_id, who.clone(), value));
         Ok(())
     }
 
@@ -791,7 +2337,7 @@ impl<T: Trait> MultiReservableCurrency<T::AccountId> for Module<T> {
         value: Self::Balance,
     ) -> Self::Balance {
         if Self::get_asset(&currency_id).is_err()
-            || Self::can_do(&currency_id, Restriction::Unreservable).is_err()
+            || Self::can_do(&currency_id, who, Restriction::Unreservable).is_err()
         {
             return Zero::zero();
         }
@@ -800,11 +2346,15 @@ impl<T: Trait> MultiReservableCurrency<T::AccountId> for Module<T> {
             return Zero::zero();
         }
 
-        let account = Self::accounts(who, currency_id);
-        let actual = account.reserved.min(value);
-        Self::set_reserved_balance(currency_id, who, account.reserved - actual);
-        Self::set_free_balance(currency_id, who, account.free + actual);
-        T::OnReceived::on_received(who, currency_id, actual);
+        let actual = Self::reserved_balance(currency_id, who).min(value);
+        Self::mutate_account(currency_id, who, |account_data| {
+            account_data.reserved -= actual;
+            account_data.free += actual;
+        });
+        // not a receipt, the balance was already owned by `who`
+        if !actual.is_zero() {
+            Self::deposit_event(RawEvent::Unreserved(currency_id, who.clone(), actual));
+        }
         value - actual
     }
 
@@ -838,6 +2388,10 @@ impl<T: Trait> MultiReservableCurrency<T::AccountId> for Module<T> {
         let from_account = Self::accounts(slashed, currency_id);
         let to_account = Self::accounts(beneficiary, currency_id);
         let actual = from_account.reserved.min(value);
+        if actual.is_zero() {
+            return Ok(value);
+        }
+        Self::ensure_min_balance(currency_id, beneficiary, actual)?;
         match status {
             BalanceStatus::Free => {
                 Self::set_free_balance(currency_id, beneficiary, to_account.free + actual);
@@ -848,6 +2402,23 @@ impl<T: Trait> MultiReservableCurrency<T::AccountId> for Module<T> {
             }
         }
         Self::set_reserved_balance(currency_id, slashed, from_account.reserved - actual);
+        Self::deposit_event(RawEvent::ReserveRepatriated(
+            currency_id,
+            slashed.clone(),
+            beneficiary.clone(),
+            actual,
+            status,
+        ));
         Ok(value - actual)
     }
 }
+
+impl<T: Trait> AddressChecker<T::CurrencyId> for Module<T> {
+    /// Check `address` by the address format of the origin chain, an unknown asset has no
+    /// valid address.
+    fn is_valid_address(currency_id: T::CurrencyId, address: &[u8]) -> bool {
+        Self::asset_info_of(currency_id)
+            .and_then(|info| Self::chain_info(info.chain))
+            .map_or(false, |chain| chain.address_format.is_valid(address))
+    }
+}
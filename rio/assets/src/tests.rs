@@ -0,0 +1,1487 @@
+use super::*;
+use frame_support::{assert_noop, assert_ok, traits::OnInitialize};
+use rio_primitives::Balance;
+use sp_runtime::DispatchError;
+
+use super::mock::{
+    constants::*, Balances, ExtBuilder, Origin, RioAssets, RioAssetsErr, System, TestEvent,
+    TestModuleId, TestRuntime,
+};
+
+fn user_token_info() -> AssetInfo<Balance> {
+    AssetInfo {
+        symbol: b"UT".to_vec(),
+        name: b"User Token".to_vec(),
+        decimals: 6,
+        desc: b"token issued by user".to_vec(),
+        chain: Chain::Rio.id(),
+        min_balance: 0,
+    }
+}
+
+#[test]
+fn issue_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(RioAssets::issue(
+            Origin::signed(ALICE),
+            USER_TOKEN,
+            user_token_info()
+        ));
+        assert_eq!(
+            RioAssets::asset_info_of(USER_TOKEN),
+            Some(user_token_info())
+        );
+        assert_eq!(
+            RioAssets::asset_ownership(USER_TOKEN),
+            Some(AssetOwnership {
+                owner: ALICE,
+                issuer: ALICE,
+                deposit: ASSET_DEPOSIT,
+            })
+        );
+        assert_eq!(Balances::reserved_balance(ALICE), ASSET_DEPOSIT);
+    });
+}
+
+#[test]
+fn issue_system_id_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            RioAssets::issue(Origin::signed(ALICE), RBTC + 1, user_token_info()),
+            RioAssetsErr::NotUserAsset,
+        );
+        assert_noop!(
+            RioAssets::issue(Origin::signed(CHRIS), USER_TOKEN, user_token_info()),
+            pallet_balances::Error::<TestRuntime, pallet_balances::DefaultInstance>::InsufficientBalance,
+        );
+        // the deposit is not kept when the asset info is invalid
+        let mut info = user_token_info();
+        info.decimals = 0;
+        assert_noop!(
+            RioAssets::issue(Origin::signed(ALICE), USER_TOKEN, info),
+            RioAssetsErr::InvalidAssetInfo,
+        );
+    });
+}
+
+#[test]
+fn mint_and_burn_only_by_issuer() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(RioAssets::issue(
+            Origin::signed(ALICE),
+            USER_TOKEN,
+            user_token_info()
+        ));
+        assert_noop!(
+            RioAssets::mint(Origin::signed(BOB), USER_TOKEN, BOB, 100),
+            RioAssetsErr::NoPermission,
+        );
+        assert_noop!(
+            RioAssets::mint(Origin::signed(ALICE), RBTC, ALICE, 100),
+            RioAssetsErr::NotUserAsset,
+        );
+
+        assert_ok!(RioAssets::mint(
+            Origin::signed(ALICE),
+            USER_TOKEN,
+            ALICE,
+            100
+        ));
+        assert_eq!(RioAssets::accounts(ALICE, USER_TOKEN).free, 100);
+        assert_eq!(RioAssets::total_issuance(USER_TOKEN), 100);
+
+        assert_ok!(RioAssets::set_issuer(
+            Origin::signed(ALICE),
+            USER_TOKEN,
+            BOB
+        ));
+        assert_noop!(
+            RioAssets::burn(Origin::signed(ALICE), USER_TOKEN, 40),
+            RioAssetsErr::NoPermission,
+        );
+        assert_ok!(RioAssets::transfer(
+            Origin::signed(ALICE),
+            BOB,
+            USER_TOKEN,
+            40
+        ));
+        assert_ok!(RioAssets::burn(Origin::signed(BOB), USER_TOKEN, 40));
+        assert_eq!(RioAssets::total_issuance(USER_TOKEN), 60);
+    });
+}
+
+#[test]
+fn transfer_ownership_moves_deposit() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(RioAssets::issue(
+            Origin::signed(ALICE),
+            USER_TOKEN,
+            user_token_info()
+        ));
+        assert_noop!(
+            RioAssets::transfer_ownership(Origin::signed(BOB), USER_TOKEN, BOB),
+            RioAssetsErr::NoPermission,
+        );
+        assert_ok!(RioAssets::transfer_ownership(
+            Origin::signed(ALICE),
+            USER_TOKEN,
+            BOB
+        ));
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert_eq!(Balances::reserved_balance(BOB), ASSET_DEPOSIT);
+        assert_eq!(RioAssets::asset_ownership(USER_TOKEN).unwrap().owner, BOB);
+        // issuer is kept
+        assert_eq!(
+            RioAssets::asset_ownership(USER_TOKEN).unwrap().issuer,
+            ALICE
+        );
+    });
+}
+
+#[test]
+fn destroy_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(RioAssets::issue(
+            Origin::signed(ALICE),
+            USER_TOKEN,
+            user_token_info()
+        ));
+        assert_ok!(RioAssets::mint(
+            Origin::signed(ALICE),
+            USER_TOKEN,
+            ALICE,
+            100
+        ));
+        assert_noop!(
+            RioAssets::destroy(Origin::signed(ALICE), USER_TOKEN, 10),
+            RioAssetsErr::AssetNotEmpty,
+        );
+        assert_ok!(RioAssets::burn(Origin::signed(ALICE), USER_TOKEN, 100));
+        assert_ok!(RioAssets::destroy(Origin::signed(ALICE), USER_TOKEN, 10));
+
+        assert_eq!(RioAssets::asset_info_of(USER_TOKEN), None);
+        assert_eq!(RioAssets::asset_ownership(USER_TOKEN), None);
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+    });
+}
+
+#[test]
+fn destroy_removes_asset_entries() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(RioAssets::issue(
+            Origin::signed(ALICE),
+            USER_TOKEN,
+            user_token_info()
+        ));
+        assert_ok!(RioAssets::approve(
+            Origin::signed(ALICE),
+            BOB,
+            USER_TOKEN,
+            10
+        ));
+        assert_ok!(RioAssets::set_account_restrictions(
+            Origin::root(),
+            BOB,
+            USER_TOKEN,
+            Restriction::Transferable.into()
+        ));
+
+        // only one entry is removed, the rest is left to the next call
+        assert_ok!(RioAssets::destroy(Origin::signed(ALICE), USER_TOKEN, 1));
+        assert!(RioAssets::asset_info_of(USER_TOKEN).is_some());
+        assert!(!RioAssets::online(USER_TOKEN));
+        assert_eq!(
+            RioAssets::destroying_asset(USER_TOKEN),
+            Some(DestroyPolicy::Burn)
+        );
+
+        assert_ok!(RioAssets::destroy(Origin::signed(ALICE), USER_TOKEN, 1));
+        assert_eq!(RioAssets::asset_info_of(USER_TOKEN), None);
+        assert_eq!(RioAssets::destroying_asset(USER_TOKEN), None);
+        assert!(!AccountRestrictions::<TestRuntime>::contains_key(
+            USER_TOKEN, BOB
+        ));
+        assert!(!Approvals::<TestRuntime>::contains_key(
+            USER_TOKEN,
+            (ALICE, BOB)
+        ));
+    });
+}
+
+#[test]
+fn root_only_calls_reject_signed() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            RioAssets::create(Origin::signed(ALICE), RBTC + 1, user_token_info()),
+            DispatchError::BadOrigin,
+        );
+    });
+}
+
+#[test]
+fn max_supply_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        // genesis endowment is 1000
+        assert_noop!(
+            RioAssets::set_max_supply(Origin::root(), RBTC, Some(999)),
+            RioAssetsErr::ExceedMaxSupply,
+        );
+        assert_ok!(RioAssets::set_max_supply(Origin::root(), RBTC, Some(1500)));
+
+        assert_ok!(<RioAssets as MultiCurrency<_>>::deposit(RBTC, &BOB, 500));
+        assert_noop!(
+            <RioAssets as MultiCurrency<_>>::deposit(RBTC, &BOB, 1),
+            RioAssetsErr::ExceedMaxSupply,
+        );
+        assert_noop!(
+            <RioAssets as MultiCurrencyExtended<_>>::update_balance(RBTC, &BOB, 1),
+            RioAssetsErr::ExceedMaxSupply,
+        );
+
+        assert_ok!(RioAssets::set_max_supply(Origin::root(), RBTC, None));
+        assert_ok!(<RioAssets as MultiCurrency<_>>::deposit(RBTC, &BOB, 1));
+    });
+}
+
+#[test]
+fn minted_and_burned_are_accounted() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(RioAssets::total_minted(RBTC), 1000);
+
+        assert_ok!(<RioAssets as MultiCurrency<_>>::deposit(RBTC, &BOB, 300));
+        assert_ok!(<RioAssets as MultiCurrency<_>>::withdraw(RBTC, &BOB, 100));
+        assert_eq!(<RioAssets as MultiCurrency<_>>::slash(RBTC, &BOB, 50), 0);
+
+        assert_eq!(RioAssets::total_minted(RBTC), 1300);
+        assert_eq!(RioAssets::total_burned(RBTC), 150);
+        assert_eq!(
+            RioAssets::total_issuance(RBTC),
+            RioAssets::total_minted(RBTC) - RioAssets::total_burned(RBTC)
+        );
+    });
+}
+
+#[test]
+fn freeze_and_thaw_account_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(<RioAssets as MultiCurrency<_>>::deposit(RBTC, &BOB, 100));
+        assert_noop!(
+            RioAssets::freeze_account(Origin::signed(BOB), ALICE, RBTC),
+            DispatchError::BadOrigin,
+        );
+        assert_ok!(RioAssets::freeze_account(Origin::root(), ALICE, RBTC));
+
+        assert_noop!(
+            RioAssets::transfer(Origin::signed(ALICE), BOB, RBTC, 10),
+            RioAssetsErr::AccountRestricted,
+        );
+        assert_noop!(
+            <RioAssets as MultiCurrency<_>>::withdraw(RBTC, &ALICE, 10),
+            RioAssetsErr::AccountRestricted,
+        );
+        // other holders are not affected, and the frozen account could still receive
+        assert_ok!(RioAssets::transfer(Origin::signed(BOB), ALICE, RBTC, 10));
+
+        assert_ok!(RioAssets::thaw_account(Origin::root(), ALICE, RBTC));
+        assert_ok!(RioAssets::transfer(Origin::signed(ALICE), BOB, RBTC, 10));
+    });
+}
+
+#[test]
+fn thaw_account_keeps_other_restrictions() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(<RioAssets as MultiCurrency<_>>::deposit(RBTC, &BOB, 100));
+        let mut restrictions: Restrictions = Restriction::Depositable.into();
+        restrictions.set(Restriction::Slashable);
+        assert_ok!(RioAssets::set_account_restrictions(
+            Origin::root(),
+            ALICE,
+            RBTC,
+            restrictions
+        ));
+        assert_ok!(RioAssets::freeze_account(Origin::root(), ALICE, RBTC));
+        assert_ok!(RioAssets::thaw_account(Origin::root(), ALICE, RBTC));
+
+        assert_eq!(RioAssets::account_restrictions(RBTC, ALICE), restrictions);
+        assert_ok!(<RioAssets as MultiCurrency<_>>::withdraw(RBTC, &ALICE, 10));
+        assert_noop!(
+            <RioAssets as MultiCurrency<_>>::deposit(RBTC, &ALICE, 10),
+            RioAssetsErr::AccountRestricted,
+        );
+
+        // no entry is left after thawing an account without restrictions
+        assert_ok!(RioAssets::freeze_account(Origin::root(), BOB, RBTC));
+        assert!(RioAssets::is_frozen(RBTC, BOB));
+        assert_ok!(RioAssets::thaw_account(Origin::root(), BOB, RBTC));
+        assert!(!AccountRestrictions::<TestRuntime>::contains_key(RBTC, BOB));
+        assert!(!RioAssets::is_frozen(RBTC, BOB));
+    });
+}
+
+#[test]
+fn thaw_account_keeps_overlapped_restrictions() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(<RioAssets as MultiCurrency<_>>::deposit(RBTC, &BOB, 100));
+        assert_ok!(RioAssets::set_account_restrictions(
+            Origin::root(),
+            ALICE,
+            RBTC,
+            Restriction::Transferable.into()
+        ));
+        assert_ok!(RioAssets::freeze_account(Origin::root(), ALICE, RBTC));
+        assert_ok!(RioAssets::thaw_account(Origin::root(), ALICE, RBTC));
+
+        assert_eq!(
+            RioAssets::account_restrictions(RBTC, ALICE),
+            Restrictions::from(Restriction::Transferable)
+        );
+        assert_noop!(
+            RioAssets::transfer(Origin::signed(ALICE), BOB, RBTC, 10),
+            RioAssetsErr::AccountRestricted,
+        );
+        // only the restrictions of the freeze are lifted
+        assert_ok!(<RioAssets as MultiCurrency<_>>::withdraw(RBTC, &ALICE, 10));
+    });
+}
+
+#[test]
+fn set_account_restrictions_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(RioAssets::set_account_restrictions(
+            Origin::root(),
+            ALICE,
+            RBTC,
+            Restriction::Depositable.into()
+        ));
+        assert_noop!(
+            <RioAssets as MultiCurrency<_>>::deposit(RBTC, &ALICE, 10),
+            RioAssetsErr::AccountRestricted,
+        );
+        assert_ok!(<RioAssets as MultiCurrency<_>>::deposit(RBTC, &BOB, 10));
+
+        assert_ok!(RioAssets::set_account_restrictions(
+            Origin::root(),
+            ALICE,
+            RBTC,
+            Restrictions::none()
+        ));
+        assert!(!AccountRestrictions::<TestRuntime>::contains_key(
+            RBTC, ALICE
+        ));
+    });
+}
+
+#[test]
+fn approve_and_transfer_from_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(RioAssets::approve(Origin::signed(ALICE), BOB, RBTC, 100));
+        assert_ok!(RioAssets::increase_allowance(
+            Origin::signed(ALICE),
+            BOB,
+            RBTC,
+            50
+        ));
+        assert_ok!(RioAssets::decrease_allowance(
+            Origin::signed(ALICE),
+            BOB,
+            RBTC,
+            30
+        ));
+        assert_eq!(RioAssets::allowance(RBTC, &ALICE, &BOB), 120);
+        assert_noop!(
+            RioAssets::decrease_allowance(Origin::signed(ALICE), BOB, RBTC, 121),
+            RioAssetsErr::AllowanceTooLow,
+        );
+
+        assert_ok!(RioAssets::transfer_from(
+            Origin::signed(BOB),
+            ALICE,
+            CHRIS,
+            RBTC,
+            100
+        ));
+        assert_eq!(RioAssets::accounts(ALICE, RBTC).free, 900);
+        assert_eq!(RioAssets::accounts(CHRIS, RBTC).free, 100);
+        assert_eq!(RioAssets::allowance(RBTC, &ALICE, &BOB), 20);
+
+        assert_noop!(
+            RioAssets::transfer_from(Origin::signed(BOB), ALICE, CHRIS, RBTC, 21),
+            RioAssetsErr::AllowanceTooLow,
+        );
+        assert_ok!(RioAssets::transfer_from(
+            Origin::signed(BOB),
+            ALICE,
+            CHRIS,
+            RBTC,
+            20
+        ));
+        assert!(!Approvals::<TestRuntime>::contains_key(RBTC, (ALICE, BOB)));
+    });
+}
+
+#[test]
+fn transfer_from_respects_restrictions_and_locks() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(RioAssets::approve(Origin::signed(ALICE), BOB, RBTC, 1000));
+
+        <RioAssets as MultiLockableCurrency<_>>::set_lock(*b"testlock", RBTC, &ALICE, 950);
+        assert_noop!(
+            RioAssets::transfer_from(Origin::signed(BOB), ALICE, CHRIS, RBTC, 100),
+            RioAssetsErr::LiquidityRestrictions,
+        );
+        <RioAssets as MultiLockableCurrency<_>>::remove_lock(*b"testlock", RBTC, &ALICE);
+
+        assert_ok!(RioAssets::update_restriction(
+            Origin::root(),
+            RBTC,
+            Restriction::Transferable.into()
+        ));
+        assert_noop!(
+            RioAssets::transfer_from(Origin::signed(BOB), ALICE, CHRIS, RBTC, 100),
+            RioAssetsErr::RestrictedAction,
+        );
+        assert_eq!(RioAssets::allowance(RBTC, &ALICE, &BOB), 1000);
+    });
+}
+
+#[test]
+fn batch_transfer_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(RioAssets::batch_transfer(
+            Origin::signed(ALICE),
+            vec![(BOB, RBTC, 100), (CHRIS, RBTC, 200), (BOB, RBTC, 50)]
+        ));
+        assert_eq!(RioAssets::accounts(ALICE, RBTC).free, 650);
+        assert_eq!(RioAssets::accounts(BOB, RBTC).free, 150);
+        assert_eq!(RioAssets::accounts(CHRIS, RBTC).free, 200);
+    });
+}
+
+#[test]
+fn batch_transfer_is_all_or_nothing() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            RioAssets::batch_transfer(
+                Origin::signed(ALICE),
+                vec![(BOB, RBTC, 600), (CHRIS, RBTC, 600)]
+            ),
+            RioAssetsErr::BalanceTooLow,
+        );
+        assert_eq!(RioAssets::accounts(ALICE, RBTC).free, 1000);
+        assert_eq!(RioAssets::accounts(BOB, RBTC).free, 0);
+
+        let legs = vec![(BOB, RBTC, 1); rio_protocol::MAX_BATCH_TRANSFER + 1];
+        assert_noop!(
+            RioAssets::batch_transfer(Origin::signed(ALICE), legs),
+            RioAssetsErr::TooManyTransfers,
+        );
+    });
+}
+
+#[test]
+fn transfer_with_memo_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(RioAssets::transfer_with_memo(
+            Origin::signed(ALICE),
+            BOB,
+            RBTC,
+            100,
+            b"deposit:42".to_vec()
+        ));
+        assert_eq!(RioAssets::accounts(ALICE, RBTC).free, 900);
+        assert_eq!(RioAssets::accounts(BOB, RBTC).free, 100);
+
+        assert_noop!(
+            RioAssets::transfer_with_memo(
+                Origin::signed(ALICE),
+                BOB,
+                RBTC,
+                100,
+                vec![0; rio_protocol::MEMO_BYTES_LEN + 1]
+            ),
+            RioAssetsErr::MemoTooLong,
+        );
+    });
+}
+
+#[test]
+fn balance_movements_emit_events() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(<RioAssets as MultiCurrency<_>>::deposit(RBTC, &BOB, 100));
+        assert_ok!(<RioAssets as MultiCurrency<_>>::withdraw(RBTC, &BOB, 10));
+        assert_ok!(<RioAssets as MultiReservableCurrency<_>>::reserve(
+            RBTC, &BOB, 50
+        ));
+        assert_eq!(
+            <RioAssets as MultiReservableCurrency<_>>::unreserve(RBTC, &BOB, 20),
+            0
+        );
+        assert_eq!(
+            <RioAssets as MultiReservableCurrency<_>>::repatriate_reserved(
+                RBTC,
+                &BOB,
+                &CHRIS,
+                10,
+                BalanceStatus::Free
+            ),
+            Ok(0)
+        );
+        assert_eq!(<RioAssets as MultiCurrency<_>>::slash(RBTC, &BOB, 70), 0);
+        // nothing is moved without reserved balance, so no event
+        assert_eq!(
+            <RioAssets as MultiReservableCurrency<_>>::unreserve(RBTC, &CHRIS, 5),
+            5
+        );
+        assert_eq!(
+            <RioAssets as MultiReservableCurrency<_>>::repatriate_reserved(
+                RBTC,
+                &CHRIS,
+                &BOB,
+                5,
+                BalanceStatus::Free
+            ),
+            Ok(5)
+        );
+        // nothing is slashed without balance, so no event
+        let nobody = 42;
+        assert_eq!(<RioAssets as MultiCurrency<_>>::slash(RBTC, &nobody, 5), 5);
+        assert_eq!(
+            <RioAssets as MultiReservableCurrency<_>>::slash_reserved(RBTC, &CHRIS, 5),
+            5
+        );
+        <RioAssets as MultiLockableCurrency<_>>::set_lock(*b"testlock", RBTC, &ALICE, 10);
+        <RioAssets as MultiLockableCurrency<_>>::extend_lock(*b"testlock", RBTC, &ALICE, 20);
+        <RioAssets as MultiLockableCurrency<_>>::remove_lock(*b"testlock", RBTC, &ALICE);
+
+        let events = System::events()
+            .into_iter()
+            .filter_map(|r| match r.event {
+                TestEvent::rio_assets(e) => Some(e),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            events,
+            vec![
+                RawEvent::Deposited(RBTC, BOB, 100),
+                RawEvent::Withdrawn(RBTC, BOB, 10),
+                RawEvent::Reserved(RBTC, BOB, 50),
+                RawEvent::Unreserved(RBTC, BOB, 20),
+                RawEvent::ReserveRepatriated(RBTC, BOB, CHRIS, 10, BalanceStatus::Free),
+                RawEvent::Slashed(RBTC, BOB, 60, 10),
+                RawEvent::LockSet(*b"testlock", RBTC, ALICE, 10),
+                RawEvent::LockSet(*b"testlock", RBTC, ALICE, 20),
+                RawEvent::LockRemoved(*b"testlock", RBTC, ALICE),
+            ]
+        );
+    });
+}
+
+#[test]
+fn empty_account_is_reaped() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(System::refs(&ALICE), 1);
+        assert_ok!(RioAssets::transfer_all(Origin::signed(ALICE), BOB, RBTC));
+        assert!(!Accounts::<TestRuntime>::contains_key(ALICE, RBTC));
+        assert_eq!(System::refs(&ALICE), 0);
+        assert_eq!(System::refs(&BOB), 1);
+
+        // the entry is kept while something is locked
+        <RioAssets as MultiLockableCurrency<_>>::set_lock(*b"testlock", RBTC, &ALICE, 10);
+        assert!(Accounts::<TestRuntime>::contains_key(ALICE, RBTC));
+        <RioAssets as MultiLockableCurrency<_>>::remove_lock(*b"testlock", RBTC, &ALICE);
+        assert!(!Accounts::<TestRuntime>::contains_key(ALICE, RBTC));
+        assert_eq!(System::refs(&ALICE), 0);
+    });
+}
+
+#[test]
+fn dust_below_min_balance_is_burned() {
+    ExtBuilder::default().build().execute_with(|| {
+        let mut info = user_token_info();
+        info.min_balance = 10;
+        assert_ok!(RioAssets::issue(Origin::signed(ALICE), USER_TOKEN, info));
+        assert_noop!(
+            RioAssets::mint(Origin::signed(ALICE), USER_TOKEN, ALICE, 9),
+            RioAssetsErr::BelowMinBalance,
+        );
+        assert_ok!(RioAssets::mint(
+            Origin::signed(ALICE),
+            USER_TOKEN,
+            ALICE,
+            100
+        ));
+
+        assert_noop!(
+            RioAssets::transfer(Origin::signed(ALICE), BOB, USER_TOKEN, 5),
+            RioAssetsErr::BelowMinBalance,
+        );
+        assert_ok!(RioAssets::transfer(
+            Origin::signed(ALICE),
+            BOB,
+            USER_TOKEN,
+            95
+        ));
+        assert!(!Accounts::<TestRuntime>::contains_key(ALICE, USER_TOKEN));
+        assert_eq!(RioAssets::accounts(BOB, USER_TOKEN).free, 95);
+        assert_eq!(RioAssets::total_issuance(USER_TOKEN), 95);
+        assert_eq!(RioAssets::total_burned(USER_TOKEN), 5);
+
+        // reserving keeps the total, so nothing is reaped
+        assert_ok!(<RioAssets as MultiReservableCurrency<_>>::reserve(
+            USER_TOKEN, &BOB, 90
+        ));
+        assert_eq!(RioAssets::accounts(BOB, USER_TOKEN).reserved, 90);
+        assert_eq!(RioAssets::total_issuance(USER_TOKEN), 95);
+    });
+}
+
+#[test]
+fn dust_is_moved_to_dust_account() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        let mut info = user_token_info();
+        info.min_balance = 10;
+        assert_ok!(RioAssets::issue(Origin::signed(ALICE), USER_TOKEN, info));
+        assert_ok!(RioAssets::mint(
+            Origin::signed(ALICE),
+            USER_TOKEN,
+            ALICE,
+            100
+        ));
+        assert_ok!(RioAssets::mint(
+            Origin::signed(ALICE),
+            USER_TOKEN,
+            DUST_ACCOUNT,
+            10
+        ));
+
+        assert_ok!(RioAssets::transfer(
+            Origin::signed(ALICE),
+            BOB,
+            USER_TOKEN,
+            95
+        ));
+        assert!(!Accounts::<TestRuntime>::contains_key(ALICE, USER_TOKEN));
+        assert_eq!(RioAssets::accounts(DUST_ACCOUNT, USER_TOKEN).free, 15);
+        assert_eq!(RioAssets::total_issuance(USER_TOKEN), 110);
+        assert_eq!(RioAssets::total_minted(USER_TOKEN), 110);
+        assert_eq!(RioAssets::total_burned(USER_TOKEN), 0);
+        assert!(System::events().iter().any(|r| r.event
+            == TestEvent::rio_assets(RawEvent::DustMoved(USER_TOKEN, ALICE, DUST_ACCOUNT, 5))));
+
+        // the dust of the dust account itself is burned
+        assert_ok!(RioAssets::transfer(
+            Origin::signed(DUST_ACCOUNT),
+            BOB,
+            USER_TOKEN,
+            10
+        ));
+        assert!(!Accounts::<TestRuntime>::contains_key(
+            DUST_ACCOUNT,
+            USER_TOKEN
+        ));
+        assert_eq!(RioAssets::total_issuance(USER_TOKEN), 105);
+        assert_eq!(RioAssets::total_burned(USER_TOKEN), 5);
+        assert!(System::events()
+            .iter()
+            .any(|r| r.event
+                == TestEvent::rio_assets(RawEvent::DustLost(USER_TOKEN, DUST_ACCOUNT, 5))));
+    });
+}
+
+#[test]
+fn destroy_asset_in_steps_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(2);
+        assert_ok!(RioAssets::snapshot(Origin::root(), RBTC));
+        assert_ok!(RioAssets::approve(Origin::signed(ALICE), CHRIS, RBTC, 100));
+        assert_ok!(RioAssets::set_account_restrictions(
+            Origin::root(),
+            CHRIS,
+            RBTC,
+            Restriction::Depositable.into()
+        ));
+        assert_ok!(RioAssets::transfer(Origin::signed(ALICE), BOB, RBTC, 100));
+        <RioAssets as MultiLockableCurrency<_>>::set_lock(*b"testlock", RBTC, &BOB, 50);
+        assert_eq!(System::refs(&BOB), 2);
+
+        assert_noop!(
+            RioAssets::destroy_asset(Origin::root(), RBTC, 1),
+            RioAssetsErr::AssetNotDestroying,
+        );
+        assert_ok!(RioAssets::start_destroy_asset(
+            Origin::root(),
+            RBTC,
+            DestroyPolicy::BurnAndRecord
+        ));
+        assert_noop!(
+            RioAssets::transfer(Origin::signed(ALICE), BOB, RBTC, 100),
+            RioAssetsErr::InvalidAsset,
+        );
+        assert_noop!(
+            RioAssets::online_asset(Origin::root(), RBTC),
+            RioAssetsErr::AssetDestroying,
+        );
+
+        assert_ok!(RioAssets::destroy_asset(Origin::root(), RBTC, 1));
+        assert!(RioAssets::asset_info_of(RBTC).is_some());
+        assert_ok!(RioAssets::destroy_asset(Origin::root(), RBTC, 1));
+        assert_eq!(RioAssets::holder_count(RBTC), 0);
+        // the restriction, the approval, 2 checkpoints and the snapshot are left
+        assert_ok!(RioAssets::destroy_asset(Origin::root(), RBTC, 4));
+        assert!(RioAssets::asset_info_of(RBTC).is_some());
+        assert_ok!(RioAssets::destroy_asset(Origin::root(), RBTC, 1));
+        assert_eq!(RioAssets::asset_info_of(RBTC), None);
+        assert!(RioAssets::is_destroyed(RBTC));
+
+        assert!(!AccountRestrictions::<TestRuntime>::contains_key(
+            RBTC, CHRIS
+        ));
+        assert_eq!(RioAssets::allowance(RBTC, &ALICE, &CHRIS), 0);
+        assert!(BalanceCheckpoints::<TestRuntime>::iter_prefix(RBTC)
+            .next()
+            .is_none());
+        assert_eq!(RioAssets::snapshot_block(RBTC, 1), None);
+        assert_eq!(RioAssets::current_snapshot_id(RBTC), 0);
+        assert!(RioAssets::asset_info_history(RBTC).is_empty());
+
+        assert!(!Accounts::<TestRuntime>::contains_key(ALICE, RBTC));
+        assert!(!Locks::<TestRuntime>::contains_key(BOB, RBTC));
+        assert_eq!(System::refs(&ALICE), 0);
+        assert_eq!(System::refs(&BOB), 0);
+        assert_eq!(RioAssets::destroyed_balance(RBTC, ALICE), 900);
+        assert_eq!(RioAssets::destroyed_balance(RBTC, BOB), 100);
+        assert_eq!(RioAssets::total_burned(RBTC), 1000);
+
+        assert_noop!(
+            RioAssets::create(Origin::root(), RBTC, user_token_info()),
+            RioAssetsErr::AssetDestroyed,
+        );
+    });
+}
+
+#[test]
+fn balance_at_snapshot_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(RioAssets::balance_at(RBTC, &ALICE, 1), None);
+        assert_noop!(
+            RioAssets::snapshot(Origin::signed(ALICE), RBTC),
+            RioAssetsErr::NoPermission,
+        );
+
+        System::set_block_number(2);
+        assert_ok!(RioAssets::snapshot(Origin::root(), RBTC));
+        assert_eq!(RioAssets::snapshot_block(RBTC, 1), Some(2));
+        assert_ok!(RioAssets::transfer(Origin::signed(ALICE), BOB, RBTC, 100));
+        assert_ok!(RioAssets::transfer(Origin::signed(ALICE), BOB, RBTC, 100));
+
+        assert_ok!(RioAssets::snapshot(Origin::root(), RBTC));
+        assert_ok!(RioAssets::snapshot(Origin::root(), RBTC));
+        assert_ok!(RioAssets::transfer(Origin::signed(BOB), CHRIS, RBTC, 50));
+
+        assert_eq!(RioAssets::balance_at(RBTC, &ALICE, 1), Some(1000));
+        assert_eq!(RioAssets::balance_at(RBTC, &ALICE, 2), Some(800));
+        assert_eq!(RioAssets::balance_at(RBTC, &ALICE, 3), Some(800));
+        assert_eq!(RioAssets::balance_at(RBTC, &BOB, 1), Some(0));
+        assert_eq!(RioAssets::balance_at(RBTC, &BOB, 2), Some(200));
+        assert_eq!(RioAssets::balance_at(RBTC, &BOB, 3), Some(200));
+        assert_eq!(RioAssets::balance_at(RBTC, &CHRIS, 3), Some(0));
+        assert_eq!(RioAssets::balance_at(RBTC, &BOB, 4), None);
+    });
+}
+
+#[test]
+fn snapshots_are_pruned() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(2);
+        assert_ok!(RioAssets::snapshot(Origin::root(), RBTC));
+        assert_ok!(RioAssets::transfer(Origin::signed(ALICE), BOB, RBTC, 100));
+        assert_ok!(RioAssets::snapshot(Origin::root(), RBTC));
+        assert_ok!(RioAssets::transfer(Origin::signed(ALICE), BOB, RBTC, 100));
+        assert_eq!(
+            RioAssets::balance_checkpoints(RBTC, ALICE),
+            vec![(1, 1000), (2, 900)]
+        );
+
+        // `MaxSnapshots` is 3 in the mock
+        assert_ok!(RioAssets::snapshot(Origin::root(), RBTC));
+        assert_ok!(RioAssets::snapshot(Origin::root(), RBTC));
+        assert_eq!(RioAssets::snapshot_block(RBTC, 1), None);
+        assert_eq!(RioAssets::snapshot_block(RBTC, 2), Some(2));
+        assert_eq!(RioAssets::balance_at(RBTC, &ALICE, 1), None);
+        assert_eq!(RioAssets::balance_at(RBTC, &ALICE, 2), Some(900));
+
+        assert_ok!(RioAssets::transfer(Origin::signed(ALICE), BOB, RBTC, 100));
+        assert_eq!(
+            RioAssets::balance_checkpoints(RBTC, ALICE),
+            vec![(2, 900), (4, 800)]
+        );
+        assert_eq!(RioAssets::balance_at(RBTC, &ALICE, 3), Some(800));
+        assert_eq!(RioAssets::balance_at(RBTC, &ALICE, 4), Some(800));
+    });
+}
+
+#[test]
+fn holders_are_indexed_and_counted() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(RioAssets::holder_count(RBTC), 1);
+        assert_ok!(RioAssets::transfer(Origin::signed(ALICE), BOB, RBTC, 100));
+        assert_ok!(RioAssets::transfer(Origin::signed(ALICE), CHRIS, RBTC, 200));
+        assert_eq!(RioAssets::holder_count(RBTC), 3);
+        assert_eq!(RioAssets::total_asset_infos()[&RBTC].holders, 3);
+
+        let mut holders = RioAssets::holders(RBTC, 0, 10);
+        holders.sort();
+        assert_eq!(holders, vec![(ALICE, 700), (BOB, 100), (CHRIS, 200)]);
+        assert_eq!(RioAssets::holders(RBTC, 1, 1).len(), 1);
+        assert_eq!(RioAssets::holders(RBTC, 3, 10), vec![]);
+
+        assert_ok!(RioAssets::transfer_all(Origin::signed(BOB), ALICE, RBTC));
+        assert_eq!(RioAssets::holder_count(RBTC), 2);
+        assert!(!AssetHolders::<TestRuntime>::contains_key(RBTC, BOB));
+    });
+}
+
+#[test]
+fn expired_locks_are_removed() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(
+            RioAssets::set_lock_with_expiry(*b"timelock", RBTC, &ALICE, 100, 1, vec![]),
+            RioAssetsErr::InvalidLockExpiry,
+        );
+        assert_noop!(
+            RioAssets::set_lock_with_expiry(
+                *b"timelock",
+                RBTC,
+                &ALICE,
+                100,
+                10,
+                vec![0; rio_protocol::LOCK_REASON_LEN + 1]
+            ),
+            RioAssetsErr::LockReasonTooLong,
+        );
+        assert_ok!(RioAssets::set_lock_with_expiry(
+            *b"timelock",
+            RBTC,
+            &ALICE,
+            100,
+            10,
+            b"airdrop".to_vec()
+        ));
+        assert_ok!(RioAssets::set_lock_with_expiry(
+            *b"timelock",
+            RBTC,
+            &BOB,
+            100,
+            10,
+            b"airdrop".to_vec()
+        ));
+        assert_ok!(RioAssets::set_lock_with_expiry(
+            *b"timelock",
+            RBTC,
+            &CHRIS,
+            100,
+            20,
+            b"airdrop".to_vec()
+        ));
+        assert_eq!(RioAssets::accounts(ALICE, RBTC).frozen, 100);
+
+        // the lock of BOB is replaced by one without expiry
+        <RioAssets as MultiLockableCurrency<_>>::set_lock(*b"timelock", RBTC, &BOB, 50);
+
+        RioAssets::on_initialize(10);
+        assert!(RioAssets::locks(ALICE, RBTC).is_empty());
+        assert_eq!(RioAssets::accounts(ALICE, RBTC).frozen, 0);
+        assert_eq!(RioAssets::locks(BOB, RBTC).len(), 1);
+        assert_eq!(RioAssets::locks(CHRIS, RBTC).len(), 1);
+
+        assert_noop!(
+            RioAssets::unlock_expired(Origin::signed(CHRIS), RBTC),
+            RioAssetsErr::NoExpiredLock,
+        );
+        System::set_block_number(20);
+        assert_ok!(RioAssets::unlock_expired(Origin::signed(CHRIS), RBTC));
+        assert!(RioAssets::locks(CHRIS, RBTC).is_empty());
+    });
+}
+
+#[test]
+fn update_asset_info_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        let old = RioAssets::asset_info_of(RBTC).unwrap();
+        let mut new = old.clone();
+        new.name = b"Renamed RBTC".to_vec();
+
+        assert_noop!(
+            RioAssets::update_asset_info(Origin::signed(ALICE), RBTC, new.clone()),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            RioAssets::update_asset_info(Origin::root(), USER_TOKEN, new.clone()),
+            RioAssetsErr::NotExistedAsset
+        );
+        let mut invalid = new.clone();
+        invalid.decimals = 0;
+        assert_noop!(
+            RioAssets::update_asset_info(Origin::root(), RBTC, invalid),
+            RioAssetsErr::InvalidAssetInfo
+        );
+        let mut invalid = new.clone();
+        invalid.symbol = vec![b'R'; rio_protocol::ASSET_SYMBOL_LEN + 1];
+        assert_noop!(
+            RioAssets::update_asset_info(Origin::root(), RBTC, invalid),
+            RioAssetsErr::InvalidAssetInfo
+        );
+
+        assert_ok!(RioAssets::update_asset_info(
+            Origin::root(),
+            RBTC,
+            new.clone()
+        ));
+        assert_eq!(RioAssets::asset_info_of(RBTC), Some(new.clone()));
+        assert!(System::events().into_iter().any(|r| r.event
+            == TestEvent::rio_assets(RawEvent::AssetInfoUpdated(RBTC, old.clone(), new.clone()))));
+    });
+}
+
+#[test]
+fn asset_info_history_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let genesis = RioAssets::asset_info_of(RBTC).unwrap();
+        let mut renamed = genesis.clone();
+        renamed.name = b"Renamed RBTC".to_vec();
+        let mut renamed_again = genesis.clone();
+        renamed_again.name = b"RBTC again".to_vec();
+
+        System::set_block_number(10);
+        assert_ok!(RioAssets::update_asset_info(
+            Origin::root(),
+            RBTC,
+            renamed.clone()
+        ));
+        System::set_block_number(20);
+        // only the last update in a block is recorded
+        assert_ok!(RioAssets::update_asset_info(
+            Origin::root(),
+            RBTC,
+            genesis.clone()
+        ));
+        assert_ok!(RioAssets::update_asset_info(
+            Origin::root(),
+            RBTC,
+            renamed_again.clone()
+        ));
+        assert_eq!(RioAssets::asset_info_history(RBTC).len(), 3);
+
+        assert_eq!(RioAssets::asset_info_at(RBTC, 0), Some(genesis.clone()));
+        assert_eq!(RioAssets::asset_info_at(RBTC, 9), Some(genesis));
+        assert_eq!(RioAssets::asset_info_at(RBTC, 10), Some(renamed.clone()));
+        assert_eq!(RioAssets::asset_info_at(RBTC, 19), Some(renamed));
+        assert_eq!(
+            RioAssets::asset_info_at(RBTC, 100),
+            Some(renamed_again.clone())
+        );
+
+        // a new asset has no info before the creation
+        assert_ok!(RioAssets::issue(
+            Origin::signed(ALICE),
+            USER_TOKEN,
+            user_token_info()
+        ));
+        assert_eq!(RioAssets::asset_info_at(USER_TOKEN, 19), None);
+        assert_eq!(
+            RioAssets::asset_info_at(USER_TOKEN, 20),
+            Some(user_token_info())
+        );
+    });
+}
+
+#[test]
+fn chain_registry_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        for chain in Chain::iterator() {
+            assert_eq!(RioAssets::chain_info(chain.id()), Some(chain.info()));
+        }
+        assert_eq!(Chain::Rio.id(), 0);
+        assert_eq!(Chain::ChainX.id(), 7);
+
+        let new_chain = 100;
+        let info = ChainInfo {
+            name: b"Binance Smart Chain".to_vec(),
+            address_format: AddressFormat::Ethereum,
+            confirmations: 15,
+        };
+        let mut asset_info = user_token_info();
+        asset_info.chain = new_chain;
+        assert_noop!(
+            RioAssets::create(Origin::root(), USER_TOKEN, asset_info.clone()),
+            RioAssetsErr::NotExistedChain
+        );
+
+        assert_noop!(
+            RioAssets::register_chain(Origin::signed(ALICE), new_chain, info.clone()),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            RioAssets::register_chain(Origin::root(), Chain::Ethereum.id(), info.clone()),
+            RioAssetsErr::ExistedChain
+        );
+        assert_noop!(
+            RioAssets::register_chain(
+                Origin::root(),
+                new_chain,
+                ChainInfo {
+                    name: vec![],
+                    ..info.clone()
+                }
+            ),
+            RioAssetsErr::InvalidChainInfo
+        );
+        assert_ok!(RioAssets::register_chain(
+            Origin::root(),
+            new_chain,
+            info.clone()
+        ));
+        assert_eq!(RioAssets::chain_info(new_chain), Some(info.clone()));
+        assert_ok!(RioAssets::create(Origin::root(), USER_TOKEN, asset_info));
+
+        let updated = ChainInfo {
+            confirmations: 20,
+            ..info
+        };
+        assert_noop!(
+            RioAssets::update_chain(Origin::root(), new_chain + 1, updated.clone()),
+            RioAssetsErr::NotExistedChain
+        );
+        assert_ok!(RioAssets::update_chain(
+            Origin::root(),
+            new_chain,
+            updated.clone()
+        ));
+        assert_eq!(RioAssets::chain_info(new_chain), Some(updated.clone()));
+        assert!(System::events().into_iter().any(|r| r.event
+            == TestEvent::rio_assets(RawEvent::ChainUpdated(new_chain, updated.clone()))));
+    });
+}
+
+#[test]
+fn received_handlers_are_dispatched_by_currency_and_account() {
+    frame_support::parameter_types! {
+        pub const GetRbtc: u32 = RBTC;
+        pub const GetBob: u64 = BOB;
+    }
+    type Handlers = ReceivedHandlers<(
+        ForCurrency<GetRbtc, ReserveReceived<TestRuntime>>,
+        ForAccount<GetBob, ReserveReceived<TestRuntime>>,
+    )>;
+
+    ExtBuilder::default().build().execute_with(|| {
+        let reserve_weight = ReserveReceived::<TestRuntime>::on_received_weight();
+        assert!(reserve_weight > 0);
+        assert_eq!(Handlers::on_received_weight(), reserve_weight * 2);
+        assert_eq!(<() as OnReceivedWeight>::on_received_weight(), 0);
+
+        assert_ok!(RioAssets::create(
+            Origin::root(),
+            USER_TOKEN,
+            user_token_info()
+        ));
+        assert_ok!(<RioAssets as MultiCurrency<_>>::deposit(
+            USER_TOKEN, &ALICE, 100
+        ));
+        assert_ok!(<RioAssets as MultiCurrency<_>>::deposit(
+            USER_TOKEN, &BOB, 100
+        ));
+
+        // RBTC of any account
+        <Handlers as OnReceived<_, _, _>>::on_received(&ALICE, RBTC, 100);
+        assert_eq!(RioAssets::reserved_balance(RBTC, &ALICE), 100);
+        // anything received by BOB
+        <Handlers as OnReceived<_, _, _>>::on_received(&BOB, USER_TOKEN, 40);
+        assert_eq!(RioAssets::reserved_balance(USER_TOKEN, &BOB), 40);
+        // neither
+        <Handlers as OnReceived<_, _, _>>::on_received(&ALICE, USER_TOKEN, 40);
+        assert_eq!(RioAssets::reserved_balance(USER_TOKEN, &ALICE), 0);
+    });
+}
+
+#[test]
+fn check_invariants_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(RioAssets::transfer(Origin::signed(ALICE), BOB, RBTC, 100));
+        assert_ok!(<RioAssets as MultiReservableCurrency<_>>::reserve(
+            RBTC, &BOB, 40
+        ));
+        <RioAssets as MultiLockableCurrency<_>>::set_lock(*b"testlock", RBTC, &ALICE, 300);
+        assert_eq!(RioAssets::check_invariants(), vec![]);
+
+        TotalIssuance::<TestRuntime>::insert(RBTC, 999);
+        assert_eq!(
+            RioAssets::check_invariants(),
+            vec![Discrepancy::TotalIssuance(RBTC, 999, 1000)]
+        );
+        TotalIssuance::<TestRuntime>::insert(RBTC, 1000);
+
+        Accounts::<TestRuntime>::mutate(ALICE, RBTC, |account| account.frozen = 200);
+        assert_eq!(
+            RioAssets::check_invariants(),
+            vec![Discrepancy::Frozen(RBTC, ALICE, 200, 300)]
+        );
+        Accounts::<TestRuntime>::mutate(ALICE, RBTC, |account| account.frozen = 300);
+
+        // ALICE takes one ref for the balance and one for the lock
+        System::dec_ref(&ALICE);
+        assert_eq!(
+            RioAssets::check_invariants(),
+            vec![Discrepancy::RefCount(ALICE, 1, 2)]
+        );
+    });
+}
+
+#[test]
+fn module_account_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        type TestModule = ModuleAccount<TestRuntime, TestModuleId>;
+        let module_account = TestModule::account_id();
+
+        assert_ok!(TestModule::deposit_from(RBTC, &ALICE, 100));
+        assert_eq!(TestModule::balance(RBTC), 100);
+        assert_eq!(
+            <RioAssets as MultiCurrency<_>>::free_balance(RBTC, &ALICE),
+            900
+        );
+
+        assert_ok!(TestModule::withdraw_to(RBTC, &BOB, 40));
+        assert_eq!(TestModule::balance(RBTC), 60);
+        assert_eq!(
+            <RioAssets as MultiCurrency<_>>::free_balance(RBTC, &BOB),
+            40
+        );
+        assert_noop!(
+            TestModule::withdraw_to(RBTC, &BOB, 61),
+            RioAssetsErr::BalanceTooLow,
+        );
+
+        assert_eq!(
+            RioAssets::module_account_balances(&[TestModuleId::get()]),
+            vec![(
+                TestModuleId::get(),
+                module_account,
+                vec![(
+                    RBTC,
+                    AccountData {
+                        free: 60,
+                        reserved: 0,
+                        frozen: 0,
+                    }
+                )]
+            )]
+        );
+    });
+}
+
+#[test]
+fn pause_asset_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let reason = PauseReason {
+            code: 1,
+            text: b"maintenance".to_vec(),
+        };
+        assert_noop!(
+            RioAssets::pause_asset(
+                Origin::root(),
+                RBTC,
+                Pause::Offline,
+                PauseReason {
+                    code: 1,
+                    text: vec![0; rio_protocol::PAUSE_REASON_LEN + 1],
+                }
+            ),
+            RioAssetsErr::PauseReasonTooLong,
+        );
+        assert_noop!(
+            RioAssets::unpause_asset(Origin::root(), RBTC),
+            RioAssetsErr::AssetNotPaused,
+        );
+
+        // offline window
+        assert_ok!(RioAssets::pause_asset(
+            Origin::root(),
+            RBTC,
+            Pause::Offline,
+            reason.clone()
+        ));
+        assert!(!RioAssets::online(RBTC));
+        assert_eq!(
+            RioAssets::paused_asset(RBTC).map(|paused| paused.reason),
+            Some(reason.clone())
+        );
+        assert_noop!(
+            RioAssets::pause_asset(Origin::root(), RBTC, Pause::Offline, reason.clone()),
+            RioAssetsErr::AssetPaused,
+        );
+        assert_ok!(RioAssets::unpause_asset(Origin::root(), RBTC));
+        assert!(RioAssets::online(RBTC));
+        assert_eq!(RioAssets::paused_asset(RBTC), None);
+
+        // the restrictions before the pause are restored
+        let restrictions: Restrictions = Restriction::Depositable.into();
+        assert_ok!(RioAssets::update_restriction(
+            Origin::root(),
+            RBTC,
+            restrictions
+        ));
+        assert_ok!(RioAssets::pause_asset(
+            Origin::root(),
+            RBTC,
+            Pause::Restrict(Restrictions::frozen()),
+            reason
+        ));
+        assert_noop!(
+            RioAssets::transfer(Origin::signed(ALICE), BOB, RBTC, 100),
+            RioAssetsErr::RestrictedAction,
+        );
+        assert_ok!(RioAssets::unpause_asset(Origin::root(), RBTC));
+        assert_eq!(RioAssets::asset_restrictions(RBTC), restrictions);
+        assert_ok!(RioAssets::transfer(Origin::signed(ALICE), BOB, RBTC, 100));
+    });
+}
+
+#[test]
+fn paused_asset_state_is_kept() {
+    ExtBuilder::default().build().execute_with(|| {
+        let reason = PauseReason {
+            code: 1,
+            text: b"maintenance".to_vec(),
+        };
+
+        // the state restored by `unpause_asset` could not be changed
+        assert_ok!(RioAssets::pause_asset(
+            Origin::root(),
+            RBTC,
+            Pause::Restrict(Restrictions::frozen()),
+            reason.clone()
+        ));
+        assert_noop!(
+            RioAssets::update_restriction(Origin::root(), RBTC, Restrictions::none()),
+            RioAssetsErr::AssetPaused,
+        );
+        assert_noop!(
+            RioAssets::online_asset(Origin::root(), RBTC),
+            RioAssetsErr::AssetPaused,
+        );
+        assert_ok!(RioAssets::unpause_asset(Origin::root(), RBTC));
+
+        // put offline during the pause, kept offline by the unpause
+        assert_ok!(RioAssets::pause_asset(
+            Origin::root(),
+            RBTC,
+            Pause::Offline,
+            reason
+        ));
+        assert_ok!(RioAssets::offline_asset(Origin::root(), RBTC));
+        assert_ok!(RioAssets::unpause_asset(Origin::root(), RBTC));
+        assert!(!RioAssets::online(RBTC));
+        assert_ok!(RioAssets::online_asset(Origin::root(), RBTC));
+        assert!(RioAssets::online(RBTC));
+    });
+}
+
+#[test]
+fn address_format_works() {
+    let valid: &[(AddressFormat, &[u8])] = &[
+        (
+            AddressFormat::Bitcoin,
+            b"1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa",
+        ),
+        (
+            AddressFormat::Bitcoin,
+            b"3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy",
+        ),
+        (
+            AddressFormat::Litecoin,
+            b"LVg2kJoFNg45Nbpy53h7Fe1wKyeXVRhMH9",
+        ),
+        (
+            AddressFormat::Litecoin,
+            b"MTf4tP1TCNBn8dNkyxeBVoPrFCcVzxJvvh",
+        ),
+        (
+            AddressFormat::Bitcoin,
+            b"bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+        ),
+        (
+            AddressFormat::Bitcoin,
+            b"BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4",
+        ),
+        (
+            AddressFormat::Litecoin,
+            b"ltc1qw508d6qejxtdg4y5r3zarvary0c5xw7kgmn4n9",
+        ),
+        (
+            AddressFormat::Bitcoin,
+            b"bc1pqqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0sg5tmnz",
+        ),
+        (
+            AddressFormat::Ethereum,
+            b"0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        ),
+        (
+            AddressFormat::Ethereum,
+            b"0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+        ),
+        (
+            AddressFormat::Ethereum,
+            b"0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed",
+        ),
+        (AddressFormat::Eos, b"eosio.token"),
+        (AddressFormat::Eos, b"riodefi12345"),
+        (
+            AddressFormat::Ss58(0),
+            b"15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5",
+        ),
+        (
+            AddressFormat::Ss58(2),
+            b"HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F",
+        ),
+        (
+            AddressFormat::Ss58(42),
+            b"5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+        ),
+        (AddressFormat::Any, b"some_address"),
+    ];
+    for (format, address) in valid {
+        assert!(format.is_valid(address), "{:?} {:?}", format, address);
+    }
+
+    let invalid: &[(AddressFormat, &[u8])] = &[
+        // checksum
+        (
+            AddressFormat::Bitcoin,
+            b"1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNb",
+        ),
+        // out of the alphabet
+        (
+            AddressFormat::Bitcoin,
+            b"1A1zP1eP5QGefi2DMPTfTL5SLmv7Div0Na",
+        ),
+        (
+            AddressFormat::Bitcoin,
+            b"bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5",
+        ),
+        // mixed case
+        (
+            AddressFormat::Bitcoin,
+            b"bc1qw508d6qejxtdg4y5r3zarvary0c5xw7KV8F3T4",
+        ),
+        // bech32 checksum for a version 1 program
+        (
+            AddressFormat::Bitcoin,
+            b"bc1pqqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0sagmhkq",
+        ),
+        (
+            AddressFormat::Bitcoin,
+            b"0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        ),
+        // the addresses of Litecoin and the Bitcoin testnet
+        (
+            AddressFormat::Bitcoin,
+            b"LVg2kJoFNg45Nbpy53h7Fe1wKyeXVRhMH9",
+        ),
+        (
+            AddressFormat::Bitcoin,
+            b"ltc1qw508d6qejxtdg4y5r3zarvary0c5xw7kgmn4n9",
+        ),
+        (
+            AddressFormat::Bitcoin,
+            b"mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn",
+        ),
+        (
+            AddressFormat::Bitcoin,
+            b"2MzQwSSnBHWHqSAqtTVQ6v47XtaisrJa1Vc",
+        ),
+        (
+            AddressFormat::Bitcoin,
+            b"tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx",
+        ),
+        (
+            AddressFormat::Litecoin,
+            b"1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa",
+        ),
+        (
+            AddressFormat::Litecoin,
+            b"bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+        ),
+        // valid checksums without a program
+        (AddressFormat::Litecoin, b"ltc1pdtjxt"),
+        (AddressFormat::Bitcoin, b"tb1dclvmr"),
+        (AddressFormat::Bitcoin, b"c1v9gszs"),
+        (
+            AddressFormat::Ethereum,
+            b"0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeaEd",
+        ),
+        (
+            AddressFormat::Ethereum,
+            b"0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA",
+        ),
+        (
+            AddressFormat::Ethereum,
+            b"5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed00",
+        ),
+        (AddressFormat::Eos, b""),
+        (AddressFormat::Eos, b"eosio.token."),
+        (AddressFormat::Eos, b"EOSIO"),
+        (AddressFormat::Eos, b"eosio6"),
+        (AddressFormat::Eos, b"riodefi123451"),
+        (
+            AddressFormat::Ss58(0),
+            b"15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp6",
+        ),
+        (
+            AddressFormat::Ss58(0),
+            b"1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa",
+        ),
+        // a Polkadot address on Kusama and a Kusama address on Polkadot
+        (
+            AddressFormat::Ss58(2),
+            b"15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5",
+        ),
+        (
+            AddressFormat::Ss58(0),
+            b"HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F",
+        ),
+        (
+            AddressFormat::Ss58(44),
+            b"5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+        ),
+    ];
+    for (format, address) in invalid {
+        assert!(!format.is_valid(address), "{:?} {:?}", format, address);
+    }
+
+    ExtBuilder::default().build().execute_with(|| {
+        // RBTC is an asset from Bitcoin
+        assert!(<RioAssets as AddressChecker<_>>::is_valid_address(
+            RBTC,
+            b"1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"
+        ));
+        assert!(!<RioAssets as AddressChecker<_>>::is_valid_address(
+            RBTC,
+            b"0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        ));
+        assert!(!<RioAssets as AddressChecker<_>>::is_valid_address(
+            RBTC,
+            b"LVg2kJoFNg45Nbpy53h7Fe1wKyeXVRhMH9"
+        ));
+        assert!(!<RioAssets as AddressChecker<_>>::is_valid_address(
+            USER_TOKEN,
+            b"1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"
+        ));
+    });
+}
@@ -3,10 +3,16 @@ use codec::{Decode, Encode};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
-use sp_runtime::RuntimeDebug;
-use sp_std::{prelude::*, slice::Iter};
+use sp_runtime::{traits::AccountIdConversion, ModuleId, RuntimeDebug};
+use sp_std::{marker::PhantomData, prelude::*, slice::Iter};
 
-use frame_support::dispatch::DispatchResult;
+use frame_support::{
+    dispatch::{DispatchError, DispatchResult},
+    traits::Get,
+    weights::Weight,
+};
+
+use orml_traits::{MultiCurrency, MultiReservableCurrency, OnReceived};
 
 use rio_primitives::Text;
 
@@ -30,7 +36,12 @@ macro_rules! define_enum {
     }
 }
 
+/// The id of an origin chain in the chain registry.
+pub type ChainId = u32;
+
 define_enum!(
+    /// The origin chains before the chain registry, they are registered with `Chain::id` as
+    /// the built-in chains.
     #[derive(PartialEq, Eq, Ord, PartialOrd, Clone, Copy, Encode, Decode, RuntimeDebug)]
     #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     Chain {
@@ -45,9 +56,77 @@ define_enum!(
     }
 );
 
+impl Chain {
+    /// The id in the chain registry, i.e. the index in the enum.
+    pub fn id(self) -> ChainId {
+        self as ChainId
+    }
+
+    /// The registry info of a built-in chain.
+    pub fn info(self) -> ChainInfo {
+        let (name, address_format, confirmations): (&[u8], _, _) = match self {
+            Chain::Rio => (b"Rio", AddressFormat::Ss58(42), 0),
+            Chain::Bitcoin => (b"Bitcoin", AddressFormat::Bitcoin, 6),
+            Chain::Litecoin => (b"Litecoin", AddressFormat::Litecoin, 12),
+            Chain::Ethereum => (b"Ethereum", AddressFormat::Ethereum, 12),
+            Chain::EOS => (b"EOS", AddressFormat::Eos, 360),
+            Chain::Polkadot => (b"Polkadot", AddressFormat::Ss58(0), 1),
+            Chain::Kusama => (b"Kusama", AddressFormat::Ss58(2), 1),
+            Chain::ChainX => (b"ChainX", AddressFormat::Ss58(44), 1),
+        };
+        ChainInfo {
+            name: name.to_vec(),
+            address_format,
+            confirmations,
+        }
+    }
+}
+
+/// The format of the addresses on a chain.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum AddressFormat {
+    /// Addresses are not checked.
+    Any,
+    /// Base58check or bech32 addresses of the Bitcoin mainnet.
+    Bitcoin,
+    /// Hex addresses of 20 bytes, e.g. Ethereum and the EVM networks.
+    Ethereum,
+    /// Account names of EOS.
+    Eos,
+    /// SS58 addresses of the substrate based chains with the network prefix.
+    Ss58(u16),
+    /// Base58check or bech32 addresses of the Litecoin mainnet.
+    Litecoin,
+}
+
+/// An origin chain in the chain registry.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ChainInfo {
+    pub name: Text,
+    pub address_format: AddressFormat,
+    /// The confirmations required for a deposit from this chain.
+    pub confirmations: u32,
+}
+
 #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-pub struct AssetInfo {
+pub struct AssetInfo<Balance> {
+    /// the origin chain in the chain registry
+    pub chain: ChainId,
+    pub symbol: Text,
+    pub name: Text,
+    pub decimals: u8,
+    pub desc: Text,
+    /// an account is reaped when its total balance falls below it, zero means no minimum
+    pub min_balance: Balance,
+}
+
+/// `AssetInfo` before `Releases::V1`, only used in the migration
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub struct AssetInfoV0 {
     pub chain: Chain,
     pub symbol: Text,
     pub name: Text,
@@ -55,6 +134,229 @@ pub struct AssetInfo {
     pub desc: Text,
 }
 
+/// `AssetInfo` before `Releases::V6`, only used in the migration
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub struct AssetInfoV1<Balance> {
+    pub chain: Chain,
+    pub symbol: Text,
+    pub name: Text,
+    pub decimals: u8,
+    pub desc: Text,
+    pub min_balance: Balance,
+}
+
+impl AssetInfoV0 {
+    pub fn upgrade<Balance: Default>(self) -> AssetInfoV1<Balance> {
+        AssetInfoV1 {
+            chain: self.chain,
+            symbol: self.symbol,
+            name: self.name,
+            decimals: self.decimals,
+            desc: self.desc,
+            min_balance: Default::default(),
+        }
+    }
+}
+
+impl<Balance> AssetInfoV1<Balance> {
+    pub fn upgrade(self) -> AssetInfo<Balance> {
+        AssetInfo {
+            chain: self.chain.id(),
+            symbol: self.symbol,
+            name: self.name,
+            decimals: self.decimals,
+            desc: self.desc,
+            min_balance: self.min_balance,
+        }
+    }
+}
+
+/// Storage version of the module.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Releases {
+    /// Original version of the module.
+    V0,
+    /// `AssetInfo` carries the minimum balance, and zero balance entries are reaped.
+    V1,
+    /// The holders of an asset are indexed in `AssetHolders`.
+    V2,
+    /// The holders of an asset are counted in `HolderCount`.
+    V3,
+    /// `BalanceLock` carries the expiry and the reason.
+    V4,
+    /// The infos of the assets are recorded in `AssetInfoHistory`.
+    V5,
+    /// `AssetInfo` references a chain in the chain registry.
+    V6,
+}
+
+impl Default for Releases {
+    fn default() -> Self {
+        Releases::V0
+    }
+}
+
+/// Handler for the dust left in an account when its balance entry is reaped.
+///
+/// Returns the dust not taken by the handler, it's burned from the total issuance.
+pub trait OnDust<AccountId, CurrencyId, Balance> {
+    fn on_dust(who: &AccountId, currency_id: CurrencyId, amount: Balance) -> Balance;
+}
+
+impl<AccountId, CurrencyId, Balance> OnDust<AccountId, CurrencyId, Balance> for () {
+    fn on_dust(_: &AccountId, _: CurrencyId, amount: Balance) -> Balance {
+        amount
+    }
+}
+
+/// Move the dust into the account returned by `GetAccountId`, e.g. the treasury. The dust is
+/// burned if that account would still be below the `min_balance` with it.
+pub struct TransferDust<T, GetAccountId>(PhantomData<(T, GetAccountId)>);
+
+impl<T, GetAccountId> OnDust<T::AccountId, T::CurrencyId, T::Balance>
+    for TransferDust<T, GetAccountId>
+where
+    T: Trait,
+    GetAccountId: Get<T::AccountId>,
+{
+    fn on_dust(who: &T::AccountId, currency_id: T::CurrencyId, amount: T::Balance) -> T::Balance {
+        Module::<T>::move_dust(currency_id, who, &GetAccountId::get(), amount)
+    }
+}
+
+/// The weight of `Trait::OnReceived` or a handler of it, the weight of `Trait::OnReceived` is
+/// added to the calls for each receiving.
+pub trait OnReceivedWeight {
+    fn on_received_weight() -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl OnReceivedWeight for Tuple {
+    fn on_received_weight() -> Weight {
+        let mut weight: Weight = 0;
+        for_tuples!( #( weight = weight.saturating_add(Tuple::on_received_weight()); )* );
+        weight
+    }
+}
+
+/// A handler of the balance received by an account, see `ReceivedHandlers`. The max weight of
+/// `on_received` is given by `OnReceivedWeight`.
+pub trait OnReceivedHandler<AccountId, CurrencyId, Balance>: OnReceivedWeight {
+    /// Whether the handler handles the balance of `currency_id` received by `who`.
+    fn matches(who: &AccountId, currency_id: CurrencyId) -> bool;
+    /// Handle the received balance, the receiving has been done and can't be failed here.
+    fn on_received(who: &AccountId, currency_id: CurrencyId, amount: Balance);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<AccountId, CurrencyId: Copy, Balance: Copy> OnReceivedHandler<AccountId, CurrencyId, Balance>
+    for Tuple
+{
+    fn matches(who: &AccountId, currency_id: CurrencyId) -> bool {
+        for_tuples!( #( if Tuple::matches(who, currency_id) { return true; } )* );
+        false
+    }
+
+    fn on_received(who: &AccountId, currency_id: CurrencyId, amount: Balance) {
+        for_tuples!( #(
+            if Tuple::matches(who, currency_id) {
+                Tuple::on_received(who, currency_id, amount);
+            }
+        )* );
+    }
+}
+
+/// The `OnReceived` hook which dispatches the received balance to the matched handlers in
+/// `Handlers`, a tuple of `OnReceivedHandler`. The weights of all the handlers are charged.
+pub struct ReceivedHandlers<Handlers>(PhantomData<Handlers>);
+
+impl<AccountId, CurrencyId, Balance, Handlers> OnReceived<AccountId, CurrencyId, Balance>
+    for ReceivedHandlers<Handlers>
+where
+    Handlers: OnReceivedHandler<AccountId, CurrencyId, Balance>,
+{
+    fn on_received(who: &AccountId, currency_id: CurrencyId, amount: Balance) {
+        Handlers::on_received(who, currency_id, amount);
+    }
+}
+
+impl<Handlers: OnReceivedWeight> OnReceivedWeight for ReceivedHandlers<Handlers> {
+    fn on_received_weight() -> Weight {
+        Handlers::on_received_weight()
+    }
+}
+
+/// Dispatch to `H` only if the balance is received by the account of `GetAccountId`.
+pub struct ForAccount<GetAccountId, H>(PhantomData<(GetAccountId, H)>);
+
+impl<AccountId, CurrencyId, Balance, GetAccountId, H>
+    OnReceivedHandler<AccountId, CurrencyId, Balance> for ForAccount<GetAccountId, H>
+where
+    AccountId: PartialEq,
+    GetAccountId: Get<AccountId>,
+    H: OnReceivedHandler<AccountId, CurrencyId, Balance>,
+{
+    fn matches(who: &AccountId, currency_id: CurrencyId) -> bool {
+        *who == GetAccountId::get() && H::matches(who, currency_id)
+    }
+
+    fn on_received(who: &AccountId, currency_id: CurrencyId, amount: Balance) {
+        H::on_received(who, currency_id, amount);
+    }
+}
+
+impl<GetAccountId, H: OnReceivedWeight> OnReceivedWeight for ForAccount<GetAccountId, H> {
+    fn on_received_weight() -> Weight {
+        H::on_received_weight()
+    }
+}
+
+/// Dispatch to `H` only if the balance is of the currency of `GetCurrencyId`.
+pub struct ForCurrency<GetCurrencyId, H>(PhantomData<(GetCurrencyId, H)>);
+
+impl<AccountId, CurrencyId, Balance, GetCurrencyId, H>
+    OnReceivedHandler<AccountId, CurrencyId, Balance> for ForCurrency<GetCurrencyId, H>
+where
+    CurrencyId: PartialEq,
+    GetCurrencyId: Get<CurrencyId>,
+    H: OnReceivedHandler<AccountId, CurrencyId, Balance>,
+{
+    fn matches(who: &AccountId, currency_id: CurrencyId) -> bool {
+        currency_id == GetCurrencyId::get() && H::matches(who, currency_id)
+    }
+
+    fn on_received(who: &AccountId, currency_id: CurrencyId, amount: Balance) {
+        H::on_received(who, currency_id, amount);
+    }
+}
+
+impl<GetCurrencyId, H: OnReceivedWeight> OnReceivedWeight for ForCurrency<GetCurrencyId, H> {
+    fn on_received_weight() -> Weight {
+        H::on_received_weight()
+    }
+}
+
+/// Reserve the received balance, e.g. to keep `LOCKED_RFUEL` locked for the fee.
+pub struct ReserveReceived<T>(PhantomData<T>);
+
+impl<T: Trait> OnReceivedHandler<T::AccountId, T::CurrencyId, T::Balance> for ReserveReceived<T> {
+    fn matches(_: &T::AccountId, _: T::CurrencyId) -> bool {
+        true
+    }
+
+    fn on_received(who: &T::AccountId, currency_id: T::CurrencyId, amount: T::Balance) {
+        // the balance just stays free if it can't be reserved, e.g. restricted
+        let _ =
+            <Module<T> as MultiReservableCurrency<T::AccountId>>::reserve(currency_id, who, amount);
+    }
+}
+
+impl<T: Trait> OnReceivedWeight for ReserveReceived<T> {
+    fn on_received_weight() -> Weight {
+        T::DbWeight::get().reads_writes(4, 2)
+    }
+}
+
 bitmask! {
     #[derive(Encode, Decode)]
     #[cfg_attr(not(feature = "std"), derive(RuntimeDebug))]
@@ -78,10 +380,28 @@ impl Default for Restrictions {
         Self::none()
     }
 }
+impl Restrictions {
+    /// the restrictions of a frozen account
+    pub fn frozen() -> Self {
+        let mut restrictions = Self::none();
+        restrictions.set(Restriction::Transferable);
+        restrictions.set(Restriction::Withdrawable);
+        restrictions.set(Restriction::Reservable);
+        restrictions
+    }
+}
 impl<T: Trait> Module<T> {
-    pub fn can_do(currency_id: &T::CurrencyId, restriction: Restriction) -> DispatchResult {
+    pub fn can_do(
+        currency_id: &T::CurrencyId,
+        who: &T::AccountId,
+        restriction: Restriction,
+    ) -> DispatchResult {
         if Self::asset_restrictions(currency_id).contains(restriction) {
             Err(Error::<T>::RestrictedAction)?
+        } else if Self::account_restrictions(currency_id, who).contains(restriction)
+            || (Self::is_frozen(currency_id, who) && Restrictions::frozen().contains(restriction))
+        {
+            Err(Error::<T>::AccountRestricted)?
         } else {
             Ok(())
         }
@@ -92,8 +412,184 @@ impl<T: Trait> Module<T> {
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub struct TotalAssetInfo<Balance> {
-    pub info: AssetInfo,
+    pub info: AssetInfo<Balance>,
     pub balance: Balance,
     pub is_online: bool,
     pub restrictions: Restrictions,
+    pub holders: u32,
+}
+
+/// How the balances of the holders are handled when an asset is destroyed by root.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug)]
+pub enum DestroyPolicy {
+    /// the balances are burned
+    Burn,
+    /// the balances are burned and recorded in `DestroyedBalances`, nothing is refunded on
+    /// chain, the holders must be settled outside the chain, e.g. on the origin chain of the
+    /// asset
+    BurnAndRecord,
+}
+
+/// How an asset is paused by `pause_asset`.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug)]
+pub enum Pause {
+    /// the asset is put offline
+    Offline,
+    /// the restrictions of the asset are replaced by these
+    Restrict(Restrictions),
+}
+
+/// Why an asset is paused, e.g. a planned maintenance of the origin chain.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+pub struct PauseReason {
+    /// a code defined outside the chain
+    pub code: u32,
+    /// no longer than `PAUSE_REASON_LEN`
+    pub text: Text,
+}
+
+/// A pause of an asset and the state restored by `unpause_asset`.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+pub struct AssetPause {
+    pub pause: Pause,
+    pub reason: PauseReason,
+    /// the online state before the pause
+    pub was_online: bool,
+    /// the restrictions of the asset before the pause
+    pub restrictions: Restrictions,
+}
+
+/// The roles of an asset issued by a user.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+pub struct AssetOwnership<AccountId, Balance> {
+    /// could change the issuer, transfer the ownership and destroy the asset
+    pub owner: AccountId,
+    /// could mint and burn the asset
+    pub issuer: AccountId,
+    /// the native currency reserved from the owner when the asset is issued
+    pub deposit: Balance,
+}
+
+/// The account derived from the `ModuleId` returned by `GetModuleId` and its sub-accounts, so
+/// that a module could hold and move the balances in its own accounts, e.g. an escrow for each
+/// withdrawal, instead of reserving them on the users.
+pub struct ModuleAccount<T, GetModuleId>(PhantomData<(T, GetModuleId)>);
+
+impl<T, GetModuleId> ModuleAccount<T, GetModuleId>
+where
+    T: Trait,
+    GetModuleId: Get<ModuleId>,
+{
+    /// The account of the module.
+    pub fn account_id() -> T::AccountId {
+        GetModuleId::get().into_account()
+    }
+
+    /// The sub-account of the module for `sub`, e.g. the id of a withdrawal.
+    pub fn sub_account_id<S: Encode>(sub: S) -> T::AccountId {
+        GetModuleId::get().into_sub_account(sub)
+    }
+
+    /// The free balance of the account of the module.
+    pub fn balance(currency_id: T::CurrencyId) -> T::Balance {
+        <Module<T> as MultiCurrency<T::AccountId>>::free_balance(currency_id, &Self::account_id())
+    }
+
+    /// The free balance of the sub-account of the module for `sub`.
+    pub fn sub_balance<S: Encode>(sub: S, currency_id: T::CurrencyId) -> T::Balance {
+        <Module<T> as MultiCurrency<T::AccountId>>::free_balance(
+            currency_id,
+            &Self::sub_account_id(sub),
+        )
+    }
+
+    /// Move `amount` from `who` into the account of the module.
+    pub fn deposit_from(
+        currency_id: T::CurrencyId,
+        who: &T::AccountId,
+        amount: T::Balance,
+    ) -> DispatchResult {
+        <Module<T> as MultiCurrency<T::AccountId>>::transfer(
+            currency_id,
+            who,
+            &Self::account_id(),
+            amount,
+        )
+    }
+
+    /// Move `amount` from the account of the module to `who`.
+    pub fn withdraw_to(
+        currency_id: T::CurrencyId,
+        who: &T::AccountId,
+        amount: T::Balance,
+    ) -> DispatchResult {
+        <Module<T> as MultiCurrency<T::AccountId>>::transfer(
+            currency_id,
+            &Self::account_id(),
+            who,
+            amount,
+        )
+    }
+
+    /// Move `amount` from `who` into the sub-account for `sub`.
+    pub fn deposit_into_sub<S: Encode>(
+        sub: S,
+        currency_id: T::CurrencyId,
+        who: &T::AccountId,
+        amount: T::Balance,
+    ) -> DispatchResult {
+        <Module<T> as MultiCurrency<T::AccountId>>::transfer(
+            currency_id,
+            who,
+            &Self::sub_account_id(sub),
+            amount,
+        )
+    }
+
+    /// Move `amount` from the sub-account for `sub` to `who`.
+    pub fn withdraw_from_sub<S: Encode>(
+        sub: S,
+        currency_id: T::CurrencyId,
+        who: &T::AccountId,
+        amount: T::Balance,
+    ) -> DispatchResult {
+        <Module<T> as MultiCurrency<T::AccountId>>::transfer(
+            currency_id,
+            &Self::sub_account_id(sub),
+            who,
+            amount,
+        )
+    }
+
+    /// Move all the free balance of the sub-account for `sub` to `who`, e.g. when an escrow is
+    /// settled, returns the amount moved.
+    pub fn drain_sub<S: Encode>(
+        sub: S,
+        currency_id: T::CurrencyId,
+        who: &T::AccountId,
+    ) -> Result<T::Balance, DispatchError> {
+        let sub_account = Self::sub_account_id(sub);
+        let amount =
+            <Module<T> as MultiCurrency<T::AccountId>>::free_balance(currency_id, &sub_account);
+        <Module<T> as MultiCurrency<T::AccountId>>::transfer(
+            currency_id,
+            &sub_account,
+            who,
+            amount,
+        )?;
+        Ok(amount)
+    }
+
+    /// Burn `amount` from the sub-account for `sub`, e.g. the assets of a finished withdrawal.
+    pub fn burn_from_sub<S: Encode>(
+        sub: S,
+        currency_id: T::CurrencyId,
+        amount: T::Balance,
+    ) -> DispatchResult {
+        <Module<T> as MultiCurrency<T::AccountId>>::withdraw(
+            currency_id,
+            &Self::sub_account_id(sub),
+            amount,
+        )
+    }
 }
@@ -11,6 +11,32 @@ pub trait WeightInfo {
     fn update_restriction() -> Weight;
     fn offline_asset() -> Weight;
     fn online_asset() -> Weight;
+    fn issue() -> Weight;
+    fn mint() -> Weight;
+    fn burn() -> Weight;
+    fn set_issuer() -> Weight;
+    fn transfer_ownership() -> Weight;
+    fn destroy(c: u32) -> Weight;
+    fn set_max_supply() -> Weight;
+    fn set_account_restrictions() -> Weight;
+    fn freeze_account() -> Weight;
+    fn thaw_account() -> Weight;
+    fn approve() -> Weight;
+    fn transfer_from() -> Weight;
+    fn batch_transfer(c: u32) -> Weight;
+    fn transfer_with_memo() -> Weight;
+    fn start_destroy_asset() -> Weight;
+    fn destroy_asset(c: u32) -> Weight;
+    fn snapshot() -> Weight;
+    fn unlock_expired() -> Weight;
+    fn register_chain() -> Weight;
+    fn update_chain() -> Weight;
+    fn increase_allowance() -> Weight;
+    fn decrease_allowance() -> Weight;
+    fn pause_asset() -> Weight;
+    fn unpause_asset() -> Weight;
+    fn schedule_pause() -> Weight;
+    fn cancel_scheduled_pause() -> Weight;
 }
 
 impl WeightInfo for () {
@@ -31,7 +57,7 @@ impl WeightInfo for () {
     fn update_asset_info() -> Weight {
         WEIGHT_PER_MICROS
             .saturating_mul(88)
-            .saturating_add(DbWeight::get().reads_writes(4, 2))
+            .saturating_add(DbWeight::get().reads_writes(5, 3))
     }
     fn update_restriction() -> Weight {
         WEIGHT_PER_MICROS
@@ -48,4 +74,149 @@ impl WeightInfo for () {
             .saturating_mul(88)
             .saturating_add(DbWeight::get().reads_writes(4, 2))
     }
+    fn issue() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(88)
+            .saturating_add(DbWeight::get().reads_writes(4, 5))
+    }
+    fn mint() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(88)
+            .saturating_add(DbWeight::get().reads_writes(5, 2))
+    }
+    fn burn() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(88)
+            .saturating_add(DbWeight::get().reads_writes(5, 2))
+    }
+    fn set_issuer() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(60)
+            .saturating_add(DbWeight::get().reads_writes(1, 1))
+    }
+    fn transfer_ownership() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(88)
+            .saturating_add(DbWeight::get().reads_writes(3, 3))
+    }
+    fn destroy(c: u32) -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(88)
+            .saturating_add(
+                WEIGHT_PER_MICROS
+                    .saturating_mul(10)
+                    .saturating_mul(c as Weight),
+            )
+            .saturating_add(DbWeight::get().reads_writes(9 + c as Weight, 8 + c as Weight))
+    }
+    fn set_max_supply() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(60)
+            .saturating_add(DbWeight::get().reads_writes(2, 1))
+    }
+    fn set_account_restrictions() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(60)
+            .saturating_add(DbWeight::get().reads_writes(1, 1))
+    }
+    fn freeze_account() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(60)
+            .saturating_add(DbWeight::get().reads_writes(1, 1))
+    }
+    fn thaw_account() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(60)
+            .saturating_add(DbWeight::get().reads_writes(0, 1))
+    }
+    fn approve() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(60)
+            .saturating_add(DbWeight::get().reads_writes(3, 1))
+    }
+    fn transfer_from() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(100)
+            .saturating_add(DbWeight::get().reads_writes(7, 3))
+    }
+    fn batch_transfer(c: u32) -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(20)
+            .saturating_add(
+                WEIGHT_PER_MICROS
+                    .saturating_mul(30)
+                    .saturating_mul(c as Weight),
+            )
+            .saturating_add(DbWeight::get().reads_writes(4 * c as Weight, 2 * c as Weight))
+    }
+    fn transfer_with_memo() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(70)
+            .saturating_add(DbWeight::get().reads_writes(4, 2))
+    }
+    fn start_destroy_asset() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(30)
+            .saturating_add(DbWeight::get().reads_writes(2, 2))
+    }
+    fn destroy_asset(c: u32) -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(100)
+            .saturating_add(
+                WEIGHT_PER_MICROS
+                    .saturating_mul(40)
+                    .saturating_mul(c as Weight),
+            )
+            .saturating_add(DbWeight::get().reads_writes(4 + 3 * c as Weight, 8 + 5 * c as Weight))
+    }
+    fn snapshot() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(35)
+            .saturating_add(DbWeight::get().reads_writes(3, 2))
+    }
+    fn unlock_expired() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(45)
+            .saturating_add(DbWeight::get().reads_writes(3, 3))
+    }
+    fn register_chain() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(25)
+            .saturating_add(DbWeight::get().reads_writes(1, 1))
+    }
+    fn update_chain() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(25)
+            .saturating_add(DbWeight::get().reads_writes(1, 1))
+    }
+    fn increase_allowance() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(60)
+            .saturating_add(DbWeight::get().reads_writes(3, 1))
+    }
+    fn decrease_allowance() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(60)
+            .saturating_add(DbWeight::get().reads_writes(2, 1))
+    }
+    fn pause_asset() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(35)
+            .saturating_add(DbWeight::get().reads_writes(5, 2))
+    }
+    fn unpause_asset() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(30)
+            .saturating_add(DbWeight::get().reads_writes(2, 2))
+    }
+    fn schedule_pause() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(50)
+            .saturating_add(DbWeight::get().reads_writes(5, 5))
+    }
+    fn cancel_scheduled_pause() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(60)
+            .saturating_add(DbWeight::get().reads_writes(7, 7))
+    }
 }
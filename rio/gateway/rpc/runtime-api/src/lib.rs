@@ -4,15 +4,21 @@ use codec::Codec;
 
 use sp_std::collections::btree_map::BTreeMap;
 
-pub use rio_gateway::{WithdrawItem, WithdrawState};
+pub use rio_gateway::{WithdrawItem, WithdrawItemV1, WithdrawState};
 
 sp_api::decl_runtime_apis! {
+    /// `WithdrawItem` carries the approvals since the version 2.
+    #[api_version(2)]
     pub trait GatewayApi<CurrencyId, AccountId, Balance> where
         CurrencyId: Codec,
         AccountId: Codec,
         Balance: Codec,
     {
+        #[changed_in(2)]
+        fn withdraw_list() -> BTreeMap<u64, (WithdrawItemV1<CurrencyId, AccountId, Balance>, Balance)>;
         fn withdraw_list() -> BTreeMap<u64, (WithdrawItem<CurrencyId, AccountId, Balance>, Balance)>;
+        #[changed_in(2)]
+        fn pending_withdraw_list() -> BTreeMap<u64, (WithdrawItemV1<CurrencyId, AccountId, Balance>, Balance)>;
         fn pending_withdraw_list() -> BTreeMap<u64, (WithdrawItem<CurrencyId, AccountId, Balance>, Balance)>;
     }
 }
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use jsonrpc_core::{Error, ErrorCode, Result};
 use jsonrpc_derive::rpc;
 
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::{generic::BlockId, traits::Block as BlockT};
 
@@ -62,13 +62,16 @@ where
     ) -> Result<BTreeMap<u64, WithdrawItem<CurrencyId, AccountId, Balance>>> {
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
-        api.withdraw_list(&at)
-            .map(|list| {
-                list.into_iter()
-                    .map(|(i, (item, fee))| (i, WithdrawItem::from_runtime_type(item, fee)))
-                    .collect()
-            })
-            .map_err(runtime_error_into_rpc_err)
+        if has_approvals::<_, Block, CurrencyId, AccountId, Balance>(&api, &at)? {
+            api.withdraw_list(&at)
+                .map(from_runtime_list)
+                .map_err(runtime_error_into_rpc_err)
+        } else {
+            #[allow(deprecated)]
+            api.withdraw_list_before_version_2(&at)
+                .map(from_runtime_list)
+                .map_err(runtime_error_into_rpc_err)
+        }
     }
 
     fn pending_withdraw_list(
@@ -77,16 +80,47 @@ where
     ) -> Result<BTreeMap<u64, WithdrawItem<CurrencyId, AccountId, Balance>>> {
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
-        api.pending_withdraw_list(&at)
-            .map(|list| {
-                list.into_iter()
-                    .map(|(i, (item, fee))| (i, WithdrawItem::from_runtime_type(item, fee)))
-                    .collect()
-            })
-            .map_err(runtime_error_into_rpc_err)
+        if has_approvals::<_, Block, CurrencyId, AccountId, Balance>(&api, &at)? {
+            api.pending_withdraw_list(&at)
+                .map(from_runtime_list)
+                .map_err(runtime_error_into_rpc_err)
+        } else {
+            #[allow(deprecated)]
+            api.pending_withdraw_list_before_version_2(&at)
+                .map(from_runtime_list)
+                .map_err(runtime_error_into_rpc_err)
+        }
     }
 }
 
+/// Whether the runtime at `at` returns the approvals of the withdrawals, i.e. `GatewayApi` of
+/// the version 2.
+fn has_approvals<Api, Block, CurrencyId, AccountId, Balance>(
+    api: &Api,
+    at: &BlockId<Block>,
+) -> Result<bool>
+where
+    Api: ApiExt<Block>,
+    Block: BlockT,
+{
+    api.has_api_with::<dyn GatewayRuntimeApi<Block, CurrencyId, AccountId, Balance>, _>(
+        at,
+        |version| version >= 2,
+    )
+    .map_err(runtime_error_into_rpc_err)
+}
+
+fn from_runtime_list<CurrencyId, AccountId, Balance, Item>(
+    list: BTreeMap<u64, (Item, Balance)>,
+) -> BTreeMap<u64, WithdrawItem<CurrencyId, AccountId, Balance>>
+where
+    Item: Into<RuntimeWithdrawItem<CurrencyId, AccountId, Balance>>,
+{
+    list.into_iter()
+        .map(|(i, (item, fee))| (i, WithdrawItem::from_runtime_type(item.into(), fee)))
+        .collect()
+}
+
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WithdrawItem<CurrencyId, AccountId, Balance> {
@@ -96,6 +130,7 @@ pub struct WithdrawItem<CurrencyId, AccountId, Balance> {
     pub addr: String,
     pub memo: String,
     pub state: WithdrawState,
+    pub approvals: Vec<AccountId>,
     pub fee: Balance,
 }
 
@@ -136,6 +171,7 @@ impl<CurrencyId, AccountId, Balance> WithdrawItem<CurrencyId, AccountId, Balance
             addr: try_hex_or_str(&item.addr),
             memo: to_string(&item.memo),
             state: item.state,
+            approvals: item.approvals,
             fee,
         }
     }
@@ -0,0 +1,536 @@
+//! Benchmarks for the rio-gateway module.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Zero;
+use sp_std::vec;
+
+const SEED: u32 = 0;
+const MAX_BYTES_LEN: usize = 80;
+
+/// a supported asset of the genesis, the benchmarks could not create a currency id
+fn supported_currency<T: Trait>() -> CurrencyIdOf<T> {
+    SupportedAssets::<T>::iter()
+        .find(|(_, supported)| *supported)
+        .map(|(currency_id, _)| currency_id)
+        .expect("benchmarks need a supported asset in the genesis")
+}
+
+/// the `i`th currency id, the benchmarks could not create a currency id
+fn benchmark_currency<T: Trait>(i: u32) -> CurrencyIdOf<T> {
+    CurrencyIdOf::<T>::decode(&mut &i.encode()[..]).expect("the currency id is encoded as u32")
+}
+
+/// an account with all the auths
+fn admin<T: Trait>() -> T::AccountId {
+    let admin: T::AccountId = account("admin", 0, SEED);
+    Admins::<T>::insert(&admin, Auths::all());
+    admin
+}
+
+/// the limits which check all the caps of a withdrawal
+fn benchmark_withdraw_limits<T: Trait>() -> WithdrawLimitsOf<T> {
+    let cap: BalanceOf<T> = 1_000_000u32.into();
+    WithdrawLimits {
+        min: Zero::zero(),
+        max: Some(cap),
+        daily_cap: Some(cap),
+        global_cap: Some((cap, 100u32.into())),
+    }
+}
+
+/// the fee schedule with all the parts of the fee
+fn benchmark_fee_schedule<T: Trait>() -> WithdrawFeeSchedule<BalanceOf<T>> {
+    WithdrawFeeSchedule {
+        fixed: 10u32.into(),
+        bps: 30,
+        min: 10u32.into(),
+        max: Some(1_000_000u32.into()),
+    }
+}
+
+/// the longest address accepted by the origin chain of `currency_id`
+fn benchmark_address<T: Trait>(currency_id: CurrencyIdOf<T>) -> Vec<u8> {
+    let candidates: [&[u8]; 5] = [
+        b"bc1pqqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0sg5tmnz",
+        b"15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5",
+        b"0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        b"1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa",
+        b"riodefi12345",
+    ];
+    let longest = vec![b'A'; MAX_BYTES_LEN];
+    if T::AddressChecker::is_valid_address(currency_id, &longest) {
+        return longest;
+    }
+    candidates
+        .iter()
+        .find(|address| T::AddressChecker::is_valid_address(currency_id, address))
+        .map(|address| address.to_vec())
+        .unwrap_or(longest)
+}
+
+/// request a withdrawal of `who` with the longest address and memo, returns the withdrawal id
+fn request_benchmark_withdraw<T: Trait>(
+    who: &T::AccountId,
+    currency_id: CurrencyIdOf<T>,
+) -> Result<u64, &'static str> {
+    let value: BalanceOf<T> = 1000u32.into();
+    T::Currency::deposit(
+        currency_id,
+        who,
+        value + Module::<T>::withdraw_fee_of(currency_id, value),
+    )?;
+
+    let withdraw_id = Module::<T>::next_withdrawal_id();
+    Module::<T>::request_withdraw(
+        RawOrigin::Signed(who.clone()).into(),
+        currency_id,
+        value,
+        benchmark_address::<T>(currency_id),
+        vec![b'M'; MAX_BYTES_LEN],
+    )?;
+    Ok(withdraw_id)
+}
+
+benchmarks! {
+    _ { }
+
+    set_auth {
+        let a in 2 .. 100;
+        let t in 0 .. 100;
+        let c in 0 .. 1000;
+
+        // revoke `Auth::Deposit` and `Auth::Withdraw` of an admin with pending confirmations,
+        // the witnesses count the entries of the genesis too
+        Admins::<T>::drain().for_each(drop);
+        DepositThreshold::<T>::drain().for_each(drop);
+        WithdrawQuorums::<T>::drain().for_each(drop);
+        let who = admin::<T>();
+        for i in 1..a {
+            let other: T::AccountId = account("admin", i, SEED);
+            Admins::<T>::insert(&other, Auths::all());
+        }
+        for i in 0..t {
+            let currency_id = benchmark_currency::<T>(i);
+            DepositThreshold::<T>::insert(currency_id, 1);
+            WithdrawQuorums::<T>::insert(currency_id, WithdrawQuorum {
+                threshold: Zero::zero(),
+                approvals: 1,
+            });
+        }
+        let currency_id = supported_currency::<T>();
+        let deposit = Deposit {
+            account_id: who.clone(),
+            amount: 1000u32.into(),
+        };
+        for i in 0..c {
+            let tx_hash = TxHash::from_low_u64_be(i as u64);
+            DepositConfirmations::<T>::insert(currency_id, tx_hash, vec![(who.clone(), deposit.clone())]);
+        }
+        let auths: Auths = Auth::Register.into();
+    }: _(RawOrigin::Root, T::Lookup::unlookup(who.clone()), auths, a, t, c)
+    verify {
+        assert_eq!(Module::<T>::admins(&who), auths);
+        assert_eq!(DepositConfirmations::<T>::iter().count(), 0);
+    }
+
+    register_asset {
+        let admin = admin::<T>();
+        let currency_id = supported_currency::<T>();
+        SupportedAssets::<T>::remove(currency_id);
+        let fee: BalanceOf<T> = 1000u32.into();
+    }: add_supported_asset(RawOrigin::Signed(admin), currency_id, fee)
+    verify {
+        assert!(Module::<T>::supported_assets(currency_id));
+        assert_eq!(Module::<T>::withdrawal_fee(currency_id), fee);
+    }
+
+    remove_asset {
+        let admin = admin::<T>();
+        let currency_id = supported_currency::<T>();
+    }: remove_supported_asset(RawOrigin::Signed(admin), currency_id)
+    verify {
+        assert!(!Module::<T>::supported_assets(currency_id));
+    }
+
+    set_bip32_info {
+        let currency_id = supported_currency::<T>();
+        let info = DepositAddrInfo::Bip32(Bip32 {
+            x_pub: vec![b'X'; MAX_BYTES_LEN * 2],
+            path: vec![b'P'; MAX_BYTES_LEN],
+        });
+    }: set_deposit_addr_info_of_asset_id(RawOrigin::Root, currency_id, info.clone())
+    verify {
+        assert_eq!(Module::<T>::deposit_addr_info_of_asset_id(currency_id), Some(info));
+    }
+
+    set_withdrawal_fee {
+        let admin = admin::<T>();
+        let currency_id = supported_currency::<T>();
+        let fee: BalanceOf<T> = 1000u32.into();
+    }: _(RawOrigin::Signed(admin), currency_id, fee)
+    verify {
+        assert_eq!(Module::<T>::withdrawal_fee(currency_id), fee);
+    }
+
+    set_withdraw_fee_schedule {
+        let admin = admin::<T>();
+        let currency_id = supported_currency::<T>();
+        let schedule = benchmark_fee_schedule::<T>();
+    }: _(RawOrigin::Signed(admin), currency_id, Some(schedule))
+    verify {
+        assert_eq!(Module::<T>::withdraw_fee_schedule(currency_id), Some(schedule));
+    }
+
+    set_withdraw_fee_account {
+        let account: T::AccountId = account("fee", 0, SEED);
+    }: _(RawOrigin::Root, Some(T::Lookup::unlookup(account.clone())))
+    verify {
+        assert_eq!(Module::<T>::withdraw_fee_account(), Some(account));
+    }
+
+    apply_deposit_address {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        MaxDepositIndex::put(Module::<T>::next_deposit_index() + 1);
+    }: apply_deposit_index(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert!(Module::<T>::deposit_index_of_account_id(&caller).is_some());
+    }
+
+    set_max_deposit_index {
+        let new_count = Module::<T>::max_deposit_index() + 1;
+    }: _(RawOrigin::Root, new_count)
+    verify {
+        assert_eq!(Module::<T>::max_deposit_index(), new_count);
+    }
+
+    set_deposit_threshold {
+        admin::<T>();
+        let currency_id = supported_currency::<T>();
+    }: _(RawOrigin::Root, currency_id, 1)
+    verify {
+        assert_eq!(Module::<T>::deposit_threshold(currency_id), 1);
+    }
+
+    deposit {
+        let admin = admin::<T>();
+        let depositor: T::AccountId = account("depositor", 0, SEED);
+        let currency_id = supported_currency::<T>();
+        let tx_hash = TxHash::repeat_byte(1);
+        let value: BalanceOf<T> = 1000u32.into();
+
+        // the last confirmation which reaches the threshold
+        let other: T::AccountId = account("admin", 1, SEED);
+        Admins::<T>::insert(&other, Auths::all());
+        DepositThreshold::<T>::insert(currency_id, 2);
+        Module::<T>::deposit(
+            RawOrigin::Signed(other).into(),
+            T::Lookup::unlookup(depositor.clone()),
+            currency_id,
+            tx_hash,
+            value,
+        )?;
+    }: _(RawOrigin::Signed(admin), T::Lookup::unlookup(depositor.clone()), currency_id, tx_hash, value)
+    verify {
+        assert!(Module::<T>::deposit_history(currency_id, tx_hash).is_some());
+        assert_eq!(T::Currency::free_balance(currency_id, &depositor), value);
+    }
+
+    remove_deposit_confirmations {
+        let admin = admin::<T>();
+        let depositor: T::AccountId = account("depositor", 0, SEED);
+        let currency_id = supported_currency::<T>();
+        let tx_hash = TxHash::repeat_byte(1);
+        DepositThreshold::<T>::insert(currency_id, 2);
+        Module::<T>::deposit(
+            RawOrigin::Signed(admin).into(),
+            T::Lookup::unlookup(depositor),
+            currency_id,
+            tx_hash,
+            1000u32.into(),
+        )?;
+    }: _(RawOrigin::Root, currency_id, tx_hash)
+    verify {
+        assert!(Module::<T>::deposit_confirmations(currency_id, tx_hash).is_empty());
+    }
+
+    set_withdraw_limits {
+        let admin = admin::<T>();
+        let currency_id = supported_currency::<T>();
+        let limits = benchmark_withdraw_limits::<T>();
+    }: _(RawOrigin::Signed(admin), currency_id, Some(limits))
+    verify {
+        assert_eq!(Module::<T>::withdraw_limits(currency_id), Some(limits));
+    }
+
+    withdraw {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let currency_id = supported_currency::<T>();
+        let value: BalanceOf<T> = 1000u32.into();
+        WithdrawLimitsOfAsset::<T>::insert(currency_id, benchmark_withdraw_limits::<T>());
+        WithdrawFeeSchedules::<T>::insert(currency_id, benchmark_fee_schedule::<T>());
+        T::Currency::deposit(currency_id, &caller, value + Module::<T>::withdraw_fee_of(currency_id, value))?;
+        let withdraw_id = Module::<T>::next_withdrawal_id();
+    }: request_withdraw(
+        RawOrigin::Signed(caller),
+        currency_id,
+        value,
+        benchmark_address::<T>(currency_id),
+        vec![b'M'; MAX_BYTES_LEN]
+    )
+    verify {
+        assert_eq!(Module::<T>::active_withdrawal_states(withdraw_id), Some(WithdrawState::Pending));
+    }
+
+    review_withdraw {
+        let admin = admin::<T>();
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let currency_id = supported_currency::<T>();
+        let withdraw_id = request_benchmark_withdraw::<T>(&caller, currency_id)?;
+        ActiveWithdrawStates::insert(withdraw_id, WithdrawState::Reviewing);
+    }: _(RawOrigin::Signed(admin), withdraw_id)
+    verify {
+        assert_eq!(Module::<T>::active_withdrawal_states(withdraw_id), Some(WithdrawState::Pending));
+    }
+
+    revoke_withdraw {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let currency_id = supported_currency::<T>();
+        let withdraw_id = request_benchmark_withdraw::<T>(&caller, currency_id)?;
+    }: cancel_withdraw(RawOrigin::Signed(caller), withdraw_id)
+    verify {
+        assert!(Module::<T>::pending_withdraws(withdraw_id).is_none());
+    }
+
+    reject_withdraw {
+        let admin = admin::<T>();
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let currency_id = supported_currency::<T>();
+        let withdraw_id = request_benchmark_withdraw::<T>(&caller, currency_id)?;
+    }: _(RawOrigin::Signed(admin), withdraw_id)
+    verify {
+        assert!(Module::<T>::pending_withdraws(withdraw_id).is_none());
+    }
+
+    set_withdraw_quorum {
+        admin::<T>();
+        let currency_id = supported_currency::<T>();
+        let quorum = WithdrawQuorum { threshold: 1000u32.into(), approvals: 1 };
+    }: _(RawOrigin::Root, currency_id, Some(quorum))
+    verify {
+        assert_eq!(Module::<T>::withdraw_quorum(currency_id), Some(quorum));
+    }
+
+    approve_withdraw {
+        let admin = admin::<T>();
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let currency_id = supported_currency::<T>();
+        let withdraw_id = request_benchmark_withdraw::<T>(&caller, currency_id)?;
+
+        // the last approval which reaches the quorum
+        let other: T::AccountId = account("admin", 1, SEED);
+        Admins::<T>::insert(&other, Auths::all());
+        WithdrawQuorums::<T>::insert(currency_id, WithdrawQuorum { threshold: Zero::zero(), approvals: 2 });
+        Module::<T>::approve_withdraw(RawOrigin::Signed(other).into(), withdraw_id)?;
+    }: _(RawOrigin::Signed(admin), withdraw_id)
+    verify {
+        assert_eq!(Module::<T>::active_withdrawal_states(withdraw_id), Some(WithdrawState::Approved));
+    }
+
+    withdraw_finish {
+        let admin = admin::<T>();
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let currency_id = supported_currency::<T>();
+        let withdraw_id = request_benchmark_withdraw::<T>(&caller, currency_id)?;
+        Module::<T>::approve_withdraw(RawOrigin::Signed(admin.clone()).into(), withdraw_id)?;
+    }: finish_withdraw(RawOrigin::Signed(admin), withdraw_id, TxHash::repeat_byte(1))
+    verify {
+        assert!(Module::<T>::pending_withdraws(withdraw_id).is_none());
+        assert!(T::Currency::reserved_balance(currency_id, &caller).is_zero());
+    }
+
+    rebroadcast {
+        let admin = admin::<T>();
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let currency_id = supported_currency::<T>();
+        let withdraw_id = request_benchmark_withdraw::<T>(&caller, currency_id)?;
+    }: _(RawOrigin::Signed(admin), withdraw_id, TxHash::repeat_byte(1))
+    verify {
+        assert_eq!(Module::<T>::active_withdrawal_states(withdraw_id), Some(WithdrawState::Pending));
+    }
+
+    modify_withdraw_state {
+        let admin = admin::<T>();
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let currency_id = supported_currency::<T>();
+        let withdraw_id = request_benchmark_withdraw::<T>(&caller, currency_id)?;
+    }: unsafe_set_withdraw_state(RawOrigin::Signed(admin), withdraw_id, Some(WithdrawState::Approved))
+    verify {
+        assert_eq!(Module::<T>::active_withdrawal_states(withdraw_id), Some(WithdrawState::Approved));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock::{ExtBuilder, TestRuntime};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn set_auth() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_set_auth::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn register_asset() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_register_asset::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn remove_asset() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_remove_asset::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn set_bip32_info() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_set_bip32_info::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn set_withdrawal_fee() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_set_withdrawal_fee::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn set_withdraw_fee_schedule() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_set_withdraw_fee_schedule::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn remove_deposit_confirmations() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_remove_deposit_confirmations::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn set_withdraw_fee_account() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_set_withdraw_fee_account::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn apply_deposit_address() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_apply_deposit_address::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn set_max_deposit_index() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_set_max_deposit_index::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn set_deposit_threshold() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_set_deposit_threshold::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn deposit() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_deposit::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn set_withdraw_limits() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_set_withdraw_limits::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn review_withdraw() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_review_withdraw::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn withdraw() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_withdraw::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn revoke_withdraw() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_revoke_withdraw::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn reject_withdraw() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_reject_withdraw::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn set_withdraw_quorum() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_set_withdraw_quorum::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn approve_withdraw() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_approve_withdraw::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn withdraw_finish() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_withdraw_finish::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn rebroadcast() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_rebroadcast::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn modify_withdraw_state() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_modify_withdraw_state::<TestRuntime>());
+        });
+    }
+}
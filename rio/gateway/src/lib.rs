@@ -1,4 +1,5 @@
 #![cfg_attr(not(feature = "std"), no_std)]
+mod benchmarking;
 #[cfg(test)]
 mod tests;
 mod types;
@@ -7,24 +8,27 @@ mod weight_info;
 #[allow(unused_imports)]
 use codec::{Decode, Encode, Error as codecErr, HasCompact, Input, Output};
 
-use sp_runtime::traits::StaticLookup;
+use sp_runtime::{
+    traits::{CheckedAdd, One, Saturating, StaticLookup, Zero},
+    DispatchError, Permill,
+};
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage, dispatch::DispatchResult, ensure,
-    IterableStorageMap,
+    traits::Get, weights::Weight, IterableStorageDoubleMap, IterableStorageMap,
 };
 use frame_system::{ensure_root, ensure_signed};
 
 use rio_primitives::{ChainAddress, Memo};
-use rio_support::{debug, error, info, warn};
+use rio_support::{debug, error, info, traits::AddressChecker, warn};
 
-use orml_traits::{MultiCurrency, MultiReservableCurrency};
+use orml_traits::{BalanceStatus, MultiCurrency, MultiReservableCurrency};
 
-use crate::types::WithdrawPhase;
+use crate::types::{Releases, WithdrawPhase};
 pub use crate::types::{
-    Auth, Auths, Bip32, Create2, Deposit, DepositAddrInfo, TxHash, WithdrawInfo, WithdrawItem,
-    WithdrawState,
+    Auth, Auths, Bip32, Create2, Deposit, DepositAddrInfo, TxHash, WithdrawFeeSchedule,
+    WithdrawInfo, WithdrawItem, WithdrawItemV1, WithdrawLimits, WithdrawQuorum, WithdrawState,
 };
 pub use weight_info::WeightInfo;
 
@@ -33,6 +37,16 @@ pub trait Trait: frame_system::Trait {
 
     type Currency: MultiCurrency<Self::AccountId> + MultiReservableCurrency<Self::AccountId>;
 
+    /// The blocks of a day for the daily cap of the withdrawals.
+    type BlocksPerDay: Get<Self::BlockNumber>;
+
+    /// The account receiving the withdrawal fees if `WithdrawFeeAccount` is not set, e.g. the
+    /// treasury.
+    type TreasuryAccount: Get<Self::AccountId>;
+
+    /// Check the withdrawal addresses on the origin chain of an asset.
+    type AddressChecker: AddressChecker<CurrencyIdOf<Self>>;
+
     type WeightInfo: WeightInfo;
 }
 
@@ -40,6 +54,22 @@ type BalanceOf<T> =
     <<T as Trait>::Currency as MultiCurrency<<T as frame_system::Trait>::AccountId>>::Balance;
 type CurrencyIdOf<T> =
     <<T as Trait>::Currency as MultiCurrency<<T as frame_system::Trait>::AccountId>>::CurrencyId;
+type WithdrawLimitsOf<T> = WithdrawLimits<BalanceOf<T>, <T as frame_system::Trait>::BlockNumber>;
+
+/// The buckets of the rolling window of the global outflow cap.
+const OUTFLOW_BUCKETS: u32 = 24;
+
+/// The outflow after a withdrawal, checked by `check_withdraw_limits`.
+struct Outflow<T: Trait> {
+    /// the day and the value requested by the account in the day, `None` if there's no daily
+    /// cap
+    account: Option<(T::BlockNumber, BalanceOf<T>)>,
+    /// the buckets of the rolling window and the bucket of the withdrawal, `None` if there's no
+    /// global cap or the withdrawal is over the cap
+    global: Option<(Vec<(T::BlockNumber, BalanceOf<T>)>, T::BlockNumber)>,
+    /// whether the withdrawal is over the global cap
+    review: bool,
+}
 
 decl_error! {
     /// Error for the gateway module.
@@ -64,6 +94,32 @@ decl_error! {
         CanNotAssignIndex,
         /// apply an invalid withdraw
         InvalidWithdraw,
+        /// the admin already confirmed this deposit
+        AlreadyConfirmed,
+        /// the threshold is zero or more than the deposit admins
+        InvalidThreshold,
+        /// the admin already approved this withdrawal
+        AlreadyApproved,
+        /// the quorum is zero or more than the withdraw admins
+        InvalidQuorum,
+        /// the max is less than the min or the window is zero
+        InvalidWithdrawLimits,
+        /// the value is less than the min withdrawal
+        WithdrawBelowMin,
+        /// the value is more than the max withdrawal
+        WithdrawAboveMax,
+        /// the withdrawals of the account would exceed the daily cap
+        ExceedDailyCap,
+        /// the bps is more than 10_000 or the max is less than the min
+        InvalidFeeSchedule,
+        /// the withdrawal address is invalid on the origin chain of the asset
+        InvalidAddress,
+        /// the deposit has no pending confirmation
+        DepositNotConfirmed,
+        /// the storage has more entries than the witness of the call
+        InvalidWitness,
+        /// the value and the fee of the withdrawal overflow
+        WithdrawOverflow,
     }
 }
 
@@ -77,6 +133,9 @@ decl_event!(
         Deposit = Deposit<<T as frame_system::Trait>::AccountId, BalanceOf<T>>,
         WithdrawInfo =
             WithdrawInfo<CurrencyIdOf<T>, <T as frame_system::Trait>::AccountId, BalanceOf<T>>,
+        WithdrawQuorum = WithdrawQuorum<BalanceOf<T>>,
+        WithdrawLimits = WithdrawLimitsOf<T>,
+        WithdrawFeeSchedule = WithdrawFeeSchedule<BalanceOf<T>>,
     {
         AuthChanged(AccountId, Auths),
         SupportedAssetAdded(AccountId, CurrencyId, Balance),
@@ -86,6 +145,35 @@ decl_event!(
         NewDepositIndex(AccountId, u64),
         MaxDepositCountSetted(u64),
         NewDepositRecord(CurrencyId, Deposit, TxHash),
+        /// The confirmations required to mint a deposit. [currency_id, threshold]
+        DepositThresholdSet(CurrencyId, u32),
+        /// A deposit is confirmed by an admin but the threshold is not reached.
+        /// [currency_id, tx_hash, admin, deposit, confirmations]
+        DepositConfirmed(CurrencyId, TxHash, AccountId, Deposit, u32),
+        /// An admin confirms a deposit which differs from the former confirmations of the tx.
+        /// [currency_id, tx_hash, admin, deposit]
+        DepositConflicted(CurrencyId, TxHash, AccountId, Deposit),
+        /// The confirmations of a deposit which would never reach the threshold are removed.
+        /// [currency_id, tx_hash]
+        DepositConfirmationsRemoved(CurrencyId, TxHash),
+        /// The quorum to approve the large withdrawals of an asset, `None` means one approval.
+        /// [currency_id, quorum]
+        WithdrawQuorumSet(CurrencyId, Option<WithdrawQuorum>),
+        /// A withdrawal is approved by an admin but the quorum is not reached.
+        /// [withdraw_id, admin, approvals]
+        WithdrawApproved(u64, AccountId, u32),
+        /// The withdrawal limits of an asset, `None` means no limit. [admin, currency_id, limits]
+        WithdrawLimitsSet(AccountId, CurrencyId, Option<WithdrawLimits>),
+        /// A withdrawal is over the global outflow cap and queued for review. [withdraw_id]
+        WithdrawQueuedForReview(u64),
+        /// The fee schedule of an asset, `None` means the flat `WithdrawalFee`.
+        /// [admin, currency_id, schedule]
+        WithdrawFeeScheduleSet(AccountId, CurrencyId, Option<WithdrawFeeSchedule>),
+        /// The account receiving the withdrawal fees, `None` means the treasury. [account]
+        WithdrawFeeAccountSet(Option<AccountId>),
+        /// The fee of a finished withdrawal is collected, `fee` is the amount actually moved
+        /// into `fee_account`. [withdraw_id, currency_id, fee_account, fee]
+        WithdrawFeeCharged(u64, CurrencyId, AccountId, Balance),
         NewPendingWithdrawRecord(u64, WithdrawInfo, Balance),
         WithdrawRebroadcasted(u64, AccountId, WithdrawState),
         WithdrawStatusChanged(u64, AccountId, WithdrawState, WithdrawState),
@@ -115,8 +203,18 @@ decl_storage! {
         /// keep a history of depoists in case of double spent
         pub DepositHistory get(fn deposit_history):
             double_map hasher(twox_64_concat) CurrencyIdOf<T>, hasher(identity) TxHash => Option<Deposit<T::AccountId, BalanceOf<T>>>;
+        /// the confirmations from distinct deposit admins required to mint a deposit of an
+        /// asset, zero means a single confirmation
+        pub DepositThreshold get(fn deposit_threshold): map hasher(twox_64_concat) CurrencyIdOf<T> => u32;
+        /// the confirmations of the deposits which have not reached the threshold
+        pub DepositConfirmations get(fn deposit_confirmations):
+            double_map hasher(twox_64_concat) CurrencyIdOf<T>, hasher(identity) TxHash => Vec<(T::AccountId, Deposit<T::AccountId, BalanceOf<T>>)>;
         /// set a fixed withdrawal fee for a asset
         pub WithdrawalFee get(fn withdrawal_fee): map hasher(twox_64_concat) CurrencyIdOf<T> => BalanceOf<T>;
+        /// the fee schedule of the withdrawals of an asset, it takes place of `WithdrawalFee`
+        pub WithdrawFeeSchedules get(fn withdraw_fee_schedule): map hasher(twox_64_concat) CurrencyIdOf<T> => Option<WithdrawFeeSchedule<BalanceOf<T>>>;
+        /// the account receiving the withdrawal fees, `TreasuryAccount` if it's `None`
+        pub WithdrawFeeAccount get(fn withdraw_fee_account): Option<T::AccountId>;
 
         /// Withdrawal Id
         pub NextWithdrawalId get(fn next_withdrawal_id): u64 = 0;
@@ -126,6 +224,25 @@ decl_storage! {
         pub ConsumedFee get(fn consumed_fee): map hasher(twox_64_concat) u64 => BalanceOf<T>;
         /// withdrawal status for an id
         pub ActiveWithdrawStates get(fn active_withdrawal_states): map hasher(twox_64_concat) u64 => Option<WithdrawState>;
+        /// the quorum to approve the large withdrawals of an asset, one approval if it's `None`
+        pub WithdrawQuorums get(fn withdraw_quorum): map hasher(twox_64_concat) CurrencyIdOf<T> => Option<WithdrawQuorum<BalanceOf<T>>>;
+        /// the limits of the withdrawals of an asset, no limit if it's `None`
+        pub WithdrawLimitsOfAsset get(fn withdraw_limits): map hasher(twox_64_concat) CurrencyIdOf<T> => Option<WithdrawLimitsOf<T>>;
+        /// the value requested by an account in a day, `(day, value)`, only for the assets with
+        /// a daily cap
+        pub AccountOutflows get(fn account_outflow):
+            double_map hasher(twox_64_concat) CurrencyIdOf<T>, hasher(blake2_128_concat) T::AccountId => (T::BlockNumber, BalanceOf<T>);
+        /// the value requested in the buckets of the rolling window, `(bucket, value)`, the
+        /// withdrawals queued for review are counted once they pass the review
+        pub GlobalOutflows get(fn global_outflows): map hasher(twox_64_concat) CurrencyIdOf<T> => Vec<(T::BlockNumber, BalanceOf<T>)>;
+        /// the day in `AccountOutflows` and the bucket in `GlobalOutflows` a withdrawal is counted
+        /// in, they are released if the withdrawal is cancelled or rejected
+        pub CountedOutflows get(fn counted_outflow): map hasher(twox_64_concat) u64 => Option<(Option<T::BlockNumber>, Option<T::BlockNumber>)>;
+        /// the admins who approved a withdrawal, kept until the withdrawal is removed
+        pub WithdrawApprovals get(fn withdraw_approvals): map hasher(twox_64_concat) u64 => Vec<T::AccountId>;
+
+        /// Storage version of the module, used by the migrations.
+        StorageVersion build(|_: &GenesisConfig<T>| Releases::V1): Releases;
     }
 
     add_extra_genesis {
@@ -154,10 +271,62 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = T::WeightInfo::set_auth()]
-        pub fn set_auth(origin, who: <T::Lookup as StaticLookup>::Source, auths: Auths) -> DispatchResult {
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight = 0;
+            if StorageVersion::get() == Releases::V0 {
+                weight += Self::migrate_to_v1();
+            }
+            weight
+        }
+
+        /// Set the auths of an admin.
+        ///
+        /// Revoking `Auth::Deposit` or `Auth::Withdraw` iterates the admins, the thresholds or
+        /// the quorums of the assets and the pending deposit confirmations. `admin_count`,
+        /// `asset_count` and `confirmation_count` are the witnesses of the entries in `Admins`,
+        /// in each of `DepositThreshold` and `WithdrawQuorums`, and in `DepositConfirmations`
+        /// for the weight, the call fails if there are more entries.
+        #[weight = T::WeightInfo::set_auth(*admin_count, *asset_count, *confirmation_count)]
+        pub fn set_auth(
+            origin,
+            who: <T::Lookup as StaticLookup>::Source,
+            auths: Auths,
+            admin_count: u32,
+            asset_count: u32,
+            confirmation_count: u32,
+        ) -> DispatchResult {
             ensure_root(origin)?;
             let who = T::Lookup::lookup(who)?;
+
+            let old = Self::admins(&who);
+            let revoke_deposit = old.contains(Auth::Deposit) && !auths.contains(Auth::Deposit);
+            if revoke_deposit {
+                // the thresholds must be reachable by the other deposit admins
+                let admins = Self::other_admins_with(&who, Auth::Deposit, admin_count)?;
+                let thresholds = DepositThreshold::<T>::iter()
+                    .map(|(_, threshold)| threshold)
+                    .collect::<Vec<_>>();
+                ensure!(thresholds.len() <= asset_count as usize, Error::<T>::InvalidWitness);
+                ensure!(
+                    thresholds.iter().all(|threshold| *threshold as usize <= admins),
+                    Error::<T>::InvalidThreshold
+                );
+            }
+            if old.contains(Auth::Withdraw) && !auths.contains(Auth::Withdraw) {
+                // the quorums must be reachable by the other withdraw admins
+                let admins = Self::other_admins_with(&who, Auth::Withdraw, admin_count)?;
+                let quorums = WithdrawQuorums::<T>::iter()
+                    .map(|(_, quorum)| quorum.approvals)
+                    .collect::<Vec<_>>();
+                ensure!(quorums.len() <= asset_count as usize, Error::<T>::InvalidWitness);
+                ensure!(
+                    quorums.iter().all(|approvals| *approvals as usize <= admins),
+                    Error::<T>::InvalidQuorum
+                );
+            }
+            if revoke_deposit {
+                Self::remove_confirmations_of(&who, confirmation_count)?;
+            }
             Admins::<T>::insert(who.clone(), auths);
             Self::deposit_event(RawEvent::AuthChanged(who, auths));
             Ok(())
@@ -210,6 +379,44 @@ decl_module! {
             Ok(())
         }
 
+        /// Set the fee schedule of the withdrawals of an asset, `None` to charge the flat
+        /// `WithdrawalFee`.
+        #[weight = T::WeightInfo::set_withdraw_fee_schedule()]
+        pub fn set_withdraw_fee_schedule(origin, currency_id: CurrencyIdOf<T>, schedule: Option<WithdrawFeeSchedule<BalanceOf<T>>>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(Self::admins(&who).contains(Auth::Register), Error::<T>::UnAuthorized);
+            ensure!(Self::supported_assets(currency_id), Error::<T>::AssetNotSupported);
+
+            match schedule {
+                Some(schedule) => {
+                    ensure!(
+                        schedule.bps <= 10_000 && schedule.max.map_or(true, |max| max >= schedule.min),
+                        Error::<T>::InvalidFeeSchedule
+                    );
+                    WithdrawFeeSchedules::<T>::insert(currency_id, schedule);
+                }
+                None => WithdrawFeeSchedules::<T>::remove(currency_id),
+            }
+
+            Self::deposit_event(RawEvent::WithdrawFeeScheduleSet(who, currency_id, schedule));
+            Ok(())
+        }
+
+        /// Set the account receiving the withdrawal fees, `None` for `TreasuryAccount`.
+        #[weight = T::WeightInfo::set_withdraw_fee_account()]
+        pub fn set_withdraw_fee_account(origin, account: Option<<T::Lookup as StaticLookup>::Source>) -> DispatchResult {
+            ensure_root(origin)?;
+            let account = account.map(T::Lookup::lookup).transpose()?;
+
+            match account {
+                Some(ref account) => WithdrawFeeAccount::<T>::put(account),
+                None => WithdrawFeeAccount::<T>::kill(),
+            }
+            Self::deposit_event(RawEvent::WithdrawFeeAccountSet(account));
+            Ok(())
+        }
+
         #[weight = T::WeightInfo::apply_deposit_address()]
         pub fn apply_deposit_index(origin) -> DispatchResult {
             let who = ensure_signed(origin)?;
@@ -243,6 +450,23 @@ decl_module! {
             Ok(())
         }
 
+        #[weight = T::WeightInfo::set_deposit_threshold()]
+        pub fn set_deposit_threshold(origin, currency_id: CurrencyIdOf<T>, threshold: u32) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(Self::supported_assets(currency_id), Error::<T>::AssetNotSupported);
+
+            let admins = Admins::<T>::iter()
+                .filter(|(_, auths)| auths.contains(Auth::Deposit))
+                .count();
+            ensure!(threshold > 0 && threshold as usize <= admins, Error::<T>::InvalidThreshold);
+
+            DepositThreshold::<T>::insert(currency_id, threshold);
+            Self::deposit_event(RawEvent::DepositThresholdSet(currency_id, threshold));
+            Ok(())
+        }
+
+        /// Confirm a deposit of the origin chain, the deposit is minted once the same deposit
+        /// of the tx is confirmed by `DepositThreshold` distinct deposit admins.
         #[weight = T::WeightInfo::deposit()]
         pub fn deposit(origin, depositor: <T::Lookup as StaticLookup>::Source, currency_id: CurrencyIdOf<T>, tx_hash: TxHash, #[compact] value: BalanceOf<T>) -> DispatchResult {
             let who = ensure_signed(origin)?;
@@ -256,13 +480,51 @@ decl_module! {
                 account_id: depositor.clone(),
                 amount: value,
             };
+            let mut confirmations = Self::deposit_confirmations(currency_id, tx_hash);
+            ensure!(confirmations.iter().all(|(admin, _)| *admin != who), Error::<T>::AlreadyConfirmed);
+            if confirmations.iter().any(|(_, confirmed)| *confirmed != deposit_record) {
+                warn!("conflicting deposit|currency_id:{:?}|tx_hash:{:?}|admin:{:?}|deposit:{:?}", currency_id, tx_hash, who, deposit_record);
+                Self::deposit_event(RawEvent::DepositConflicted(currency_id, tx_hash, who.clone(), deposit_record.clone()));
+            }
+            confirmations.push((who.clone(), deposit_record.clone()));
+
+            // the confirmations of the admins whose `Auth::Deposit` is revoked are removed by
+            // `set_auth`
+            let confirmed = confirmations
+                .iter()
+                .filter(|(_, confirmed)| *confirmed == deposit_record)
+                .count() as u32;
+            if confirmed < Self::deposit_threshold(currency_id).max(1) {
+                DepositConfirmations::<T>::insert(currency_id, tx_hash, confirmations);
+                Self::deposit_event(RawEvent::DepositConfirmed(currency_id, tx_hash, who, deposit_record, confirmed));
+                return Ok(());
+            }
+
             T::Currency::deposit(currency_id, &depositor, value)?;
+            DepositConfirmations::<T>::remove(currency_id, tx_hash);
             DepositHistory::<T>::insert(currency_id, tx_hash, deposit_record.clone());
 
             Self::deposit_event(RawEvent::NewDepositRecord(currency_id, deposit_record, tx_hash));
             Ok(())
         }
 
+        /// Remove the confirmations of a deposit which would never reach the threshold, e.g.
+        /// the admins confirmed conflicting deposits of the tx.
+        ///
+        /// The dispatch origin for this call must be `Root`.
+        #[weight = T::WeightInfo::remove_deposit_confirmations()]
+        pub fn remove_deposit_confirmations(origin, currency_id: CurrencyIdOf<T>, tx_hash: TxHash) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                DepositConfirmations::<T>::contains_key(currency_id, tx_hash),
+                Error::<T>::DepositNotConfirmed
+            );
+
+            DepositConfirmations::<T>::remove(currency_id, tx_hash);
+            Self::deposit_event(RawEvent::DepositConfirmationsRemoved(currency_id, tx_hash));
+            Ok(())
+        }
+
         #[weight = T::WeightInfo::withdraw()]
         pub fn request_withdraw(origin, currency_id: CurrencyIdOf<T>, #[compact] value: BalanceOf<T>, addr: ChainAddress, memo: Memo) -> DispatchResult {
             let who = ensure_signed(origin)?;
@@ -276,16 +538,23 @@ decl_module! {
                         memo,
                     };
             Self::withdraw_check(&info)?;
+            let outflow = Self::check_withdraw_limits(&info)?;
 
-            let fee = Self::withdrawal_fee(currency_id);
-            let real_value = value + fee;
+            let fee = Self::withdraw_fee_of(currency_id, value);
+            let real_value = value.checked_add(&fee).ok_or(Error::<T>::WithdrawOverflow)?;
             T::Currency::reserve(currency_id, &who, real_value)?;
 
             let next_id = Self::next_withdrawal_id();
             // record fee for this withdraw
             ConsumedFee::<T>::insert(next_id, fee);
             PendingWithdrawals::<T>::insert(next_id, info.clone());
-            ActiveWithdrawStates::insert(next_id, WithdrawState::Pending);
+            let review = Self::record_outflow(next_id, &info, outflow);
+            if review {
+                ActiveWithdrawStates::insert(next_id, WithdrawState::Reviewing);
+                Self::deposit_event(RawEvent::WithdrawQueuedForReview(next_id));
+            } else {
+                ActiveWithdrawStates::insert(next_id, WithdrawState::Pending);
+            }
 
             info!("withdraw apply|who:{:?}|currency_id:{:?}|value:{:?}|withdraw_id:{:?}", who, currency_id, value, next_id);
 
@@ -295,6 +564,44 @@ decl_module! {
             Ok(())
         }
 
+        /// Set the limits of the withdrawals of an asset, `None` to remove the limits.
+        #[weight = T::WeightInfo::set_withdraw_limits()]
+        pub fn set_withdraw_limits(origin, currency_id: CurrencyIdOf<T>, limits: Option<WithdrawLimitsOf<T>>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(Self::admins(&who).contains(Auth::Register), Error::<T>::UnAuthorized);
+            ensure!(Self::supported_assets(currency_id), Error::<T>::AssetNotSupported);
+
+            match limits {
+                Some(limits) => {
+                    ensure!(
+                        limits.max.map_or(true, |max| max >= limits.min)
+                            && limits.global_cap.map_or(true, |(_, window)| !window.is_zero()),
+                        Error::<T>::InvalidWithdrawLimits
+                    );
+                    WithdrawLimitsOfAsset::<T>::insert(currency_id, limits);
+                }
+                None => WithdrawLimitsOfAsset::<T>::remove(currency_id),
+            }
+
+            Self::deposit_event(RawEvent::WithdrawLimitsSet(who, currency_id, limits));
+            Ok(())
+        }
+
+        /// Pass a withdrawal queued for review, it's `Pending` for approval again.
+        #[weight = T::WeightInfo::review_withdraw()]
+        pub fn review_withdraw(origin, #[compact] withdraw_id: u64) -> DispatchResult {
+            let operator = ensure_signed(origin)?;
+            ensure!(Self::admins(&operator).contains(Auth::Withdraw), Error::<T>::UnAuthorized);
+
+            Self::handle_withdraw(withdraw_id, &operator, WithdrawPhase::Review, |state, info| {
+                info!("review withdraw|withdraw_id:{:?}|admin:{:?}", withdraw_id, operator);
+                Self::record_reviewed_outflow(withdraw_id, &info);
+                *state = WithdrawState::Pending;
+                Ok(())
+            })
+        }
+
         #[weight = T::WeightInfo::revoke_withdraw()]
         pub fn cancel_withdraw(origin, #[compact] withdraw_id: u64) -> DispatchResult {
             let operator = ensure_signed(origin)?;
@@ -306,6 +613,7 @@ decl_module! {
                 }
                 info!("cancel withdraw success|withdraw_id:{:?}|who:{:?}|currency_id:{:?}|value:{:?}", withdraw_id, info.who, info.currency_id, info.value);
                 Self::refund(withdraw_id, &info);
+                Self::release_outflow(withdraw_id, &info);
                 *state = WithdrawState::Cancelled;
                 Ok(())
             })
@@ -319,16 +627,59 @@ decl_module! {
             Self::handle_withdraw(withdraw_id, &operator, WithdrawPhase::First, |state, info| {
                 info!("reject withdraw|withdraw_id:{:?}|admin:{:?}", withdraw_id, operator);
                 Self::refund(withdraw_id, &info);
+                Self::release_outflow(withdraw_id, &info);
                 *state = WithdrawState::Rejected;
                 Ok(())
             })
         }
 
+        #[weight = T::WeightInfo::set_withdraw_quorum()]
+        pub fn set_withdraw_quorum(origin, currency_id: CurrencyIdOf<T>, quorum: Option<WithdrawQuorum<BalanceOf<T>>>) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(Self::supported_assets(currency_id), Error::<T>::AssetNotSupported);
+
+            if let Some(quorum) = quorum {
+                let admins = Admins::<T>::iter()
+                    .filter(|(_, auths)| auths.contains(Auth::Withdraw))
+                    .count();
+                ensure!(quorum.approvals > 0 && quorum.approvals as usize <= admins, Error::<T>::InvalidQuorum);
+            }
+
+            match quorum {
+                Some(quorum) => WithdrawQuorums::<T>::insert(currency_id, quorum),
+                None => WithdrawQuorums::<T>::remove(currency_id),
+            }
+            Self::deposit_event(RawEvent::WithdrawQuorumSet(currency_id, quorum));
+            Ok(())
+        }
+
+        /// Approve a pending withdrawal, it's `Approved` once the approvals of distinct
+        /// withdraw admins reach the quorum for its value.
         #[weight = T::WeightInfo::approve_withdraw()]
         pub fn approve_withdraw(origin, #[compact] withdraw_id: u64) -> DispatchResult {
             let operator = ensure_signed(origin)?;
             ensure!(Self::admins(&operator).contains(Auth::Withdraw), Error::<T>::UnAuthorized);
 
+            let info = Self::pending_withdraws(withdraw_id).ok_or(Error::<T>::WithdrawalRecordNotExisted)?;
+            ensure!(
+                Self::active_withdrawal_states(withdraw_id) == Some(WithdrawState::Pending),
+                Error::<T>::InvalidWithdrawalState
+            );
+            let mut approvals = Self::withdraw_approvals(withdraw_id);
+            ensure!(!approvals.contains(&operator), Error::<T>::AlreadyApproved);
+            approvals.push(operator.clone());
+
+            // the approvals of the admins whose `Auth::Withdraw` is revoked are not counted
+            let approved = approvals
+                .iter()
+                .filter(|admin| Self::admins(admin).contains(Auth::Withdraw))
+                .count() as u32;
+            WithdrawApprovals::<T>::insert(withdraw_id, approvals);
+            if approved < Self::required_approvals(&info) {
+                Self::deposit_event(RawEvent::WithdrawApproved(withdraw_id, operator, approved));
+                return Ok(());
+            }
+
             Self::handle_withdraw(withdraw_id, &operator, WithdrawPhase::First, |state, _info| {
                 info!("approve withdraw|withdraw_id:{:?}|admin:{:?}", withdraw_id, operator);
                 *state = WithdrawState::Approved;
@@ -397,6 +748,8 @@ impl<T: Trait> Module<T> {
     // States:
     // - Pending: Created, waiting to be approved/rejected/cancelled by admin.
     // Currency moved from 'free' balance to 'reserved'.
+    // - Reviewing: Created over the global outflow cap, waiting to be reviewed/rejected/cancelled
+    // by admin, it's `Pending` after reviewed.
     // - Cancelled: Cancelled by admin. Currency moved from 'reserved' to 'free'.
     // - Rejected: Rejected by admin. Currency moved from 'reserved' to 'free'.
     // - Approved: Approved by admin.
@@ -426,12 +779,13 @@ impl<T: Trait> Module<T> {
         );
 
         match phase {
-            WithdrawPhase::First => {
-                if state != WithdrawState::Pending {
-                    error!("handle withdraw|just allow `Pending` state in withdraw phase 1|withdraw_id:{:?}|state:{:?}", withdraw_id, state);
+            WithdrawPhase::First => match state {
+                WithdrawState::Pending | WithdrawState::Reviewing => {}
+                _ => {
+                    error!("handle withdraw|just allow `Pending` or `Reviewing` state in withdraw phase 1|withdraw_id:{:?}|state:{:?}", withdraw_id, state);
                     Err(Error::<T>::InvalidWithdrawalState)?;
                 }
-            }
+            },
             WithdrawPhase::Second => match state {
                 WithdrawState::Approved => {}
                 _ => {
@@ -439,6 +793,12 @@ impl<T: Trait> Module<T> {
                     Err(Error::<T>::InvalidWithdrawalState)?;
                 }
             },
+            WithdrawPhase::Review => {
+                if state != WithdrawState::Reviewing {
+                    error!("handle withdraw|just allow `Reviewing` state in review phase|withdraw_id:{:?}|state:{:?}", withdraw_id, state);
+                    Err(Error::<T>::InvalidWithdrawalState)?;
+                }
+            }
         }
 
         handle(&mut state, info)?;
@@ -455,8 +815,10 @@ impl<T: Trait> Module<T> {
                 // remove recorded fee for this withdraw id
                 ConsumedFee::<T>::remove(withdraw_id);
                 ActiveWithdrawStates::remove(withdraw_id);
+                WithdrawApprovals::<T>::remove(withdraw_id);
+                CountedOutflows::<T>::remove(withdraw_id);
             }
-            WithdrawState::Approved => {
+            WithdrawState::Approved | WithdrawState::Pending => {
                 ActiveWithdrawStates::insert(withdraw_id, state.clone());
             }
             _ => unreachable!("not expected withdraw state"),
@@ -491,19 +853,252 @@ impl<T: Trait> Module<T> {
             "withdraw burn|currency_id:{:?}|who:{:?}|value:{:?}|fee:{:?}",
             currency_id, who, value, fee
         );
-        T::Currency::slash_reserved(currency_id, who, value + fee);
+        T::Currency::slash_reserved(currency_id, who, value);
+
+        // the fee is collected, or burnt if it could not be moved to the fee account
+        let fee_account = Self::withdraw_fee_account().unwrap_or_else(T::TreasuryAccount::get);
+        let remaining = T::Currency::repatriate_reserved(
+            currency_id,
+            who,
+            &fee_account,
+            fee,
+            BalanceStatus::Free,
+        )
+        .unwrap_or_else(|e| {
+            warn!(
+                "withdraw fee burnt|currency_id:{:?}|who:{:?}|fee:{:?}|err:{:?}",
+                currency_id, who, fee, e
+            );
+            T::Currency::slash_reserved(currency_id, who, fee);
+            fee
+        });
+        let charged = fee.saturating_sub(remaining);
+        Self::deposit_event(RawEvent::WithdrawFeeCharged(
+            withdraw_id,
+            currency_id,
+            fee_account,
+            charged,
+        ));
+    }
+
+    /// The approvals of the withdrawals are kept in `WithdrawApprovals`, the withdrawals pending
+    /// before have no approval, the others are approved by one admin, so nothing is translated.
+    fn migrate_to_v1() -> Weight {
+        StorageVersion::put(Releases::V1);
+        T::DbWeight::get().reads_writes(1, 1)
+    }
+
+    /// The number of the admins with `auth` except `who`, fails if there are more than
+    /// `admin_count` admins.
+    fn other_admins_with(
+        who: &T::AccountId,
+        auth: Auth,
+        admin_count: u32,
+    ) -> Result<usize, DispatchError> {
+        let admins = Admins::<T>::iter().collect::<Vec<_>>();
+        ensure!(admins.len() <= admin_count as usize, Error::<T>::InvalidWitness);
+        Ok(admins
+            .iter()
+            .filter(|(admin, auths)| admin != who && auths.contains(auth))
+            .count())
+    }
+
+    /// Remove the confirmations of `admin` from the deposits which have not reached the
+    /// threshold, e.g. when its `Auth::Deposit` is revoked. Fails before removing anything if
+    /// there are more than `confirmation_count` pending deposits.
+    fn remove_confirmations_of(admin: &T::AccountId, confirmation_count: u32) -> DispatchResult {
+        let mut pending = 0usize;
+        let confirmed = DepositConfirmations::<T>::iter()
+            .inspect(|_| pending += 1)
+            .filter(|(_, _, confirmations)| confirmations.iter().any(|(who, _)| who == admin))
+            .collect::<Vec<_>>();
+        ensure!(pending <= confirmation_count as usize, Error::<T>::InvalidWitness);
+        for (currency_id, tx_hash, mut confirmations) in confirmed {
+            confirmations.retain(|(who, _)| who != admin);
+            if confirmations.is_empty() {
+                DepositConfirmations::<T>::remove(currency_id, tx_hash);
+            } else {
+                DepositConfirmations::<T>::insert(currency_id, tx_hash, confirmations);
+            }
+        }
+        Ok(())
+    }
+
+    /// The approvals required by a withdrawal, the quorum if the value reaches the threshold.
+    fn required_approvals(info: &WithdrawInfo<CurrencyIdOf<T>, T::AccountId, BalanceOf<T>>) -> u32 {
+        match Self::withdraw_quorum(info.currency_id) {
+            Some(quorum) if info.value >= quorum.threshold => quorum.approvals,
+            _ => 1,
+        }
+    }
+
+    /// Check the withdrawal against the limits of the asset, returns the outflow of the account
+    /// in the current day and of the asset in the rolling window after this withdrawal.
+    fn check_withdraw_limits(
+        info: &WithdrawInfo<CurrencyIdOf<T>, T::AccountId, BalanceOf<T>>,
+    ) -> Result<Outflow<T>, DispatchError> {
+        let limits = match Self::withdraw_limits(info.currency_id) {
+            Some(limits) => limits,
+            None => {
+                return Ok(Outflow {
+                    account: None,
+                    global: None,
+                    review: false,
+                })
+            }
+        };
+
+        ensure!(info.value >= limits.min, Error::<T>::WithdrawBelowMin);
+        if let Some(max) = limits.max {
+            ensure!(info.value <= max, Error::<T>::WithdrawAboveMax);
+        }
+        let account = match limits.daily_cap {
+            Some(cap) => {
+                let now = frame_system::Module::<T>::block_number();
+                let day = now / T::BlocksPerDay::get().max(One::one());
+                let (last_day, requested) = Self::account_outflow(info.currency_id, &info.who);
+                let requested = if last_day == day {
+                    requested.saturating_add(info.value)
+                } else {
+                    info.value
+                };
+                ensure!(requested <= cap, Error::<T>::ExceedDailyCap);
+                Some((day, requested))
+            }
+            None => None,
+        };
+
+        let (global, review) = match limits.global_cap {
+            Some((cap, window)) => {
+                let (current, mut buckets) = Self::outflow_buckets(info.currency_id, window);
+                let total = buckets
+                    .iter()
+                    .fold(info.value, |total, (_, value)| total.saturating_add(*value));
+                if total > cap {
+                    (None, true)
+                } else {
+                    Self::add_to_bucket(&mut buckets, current, info.value);
+                    (Some((buckets, current)), false)
+                }
+            }
+            None => (None, false),
+        };
+        Ok(Outflow {
+            account,
+            global,
+            review,
+        })
+    }
+
+    /// The current bucket of the rolling window of `window` blocks and the buckets in the
+    /// window, the buckets out of the window are dropped.
+    fn outflow_buckets(
+        currency_id: CurrencyIdOf<T>,
+        window: T::BlockNumber,
+    ) -> (T::BlockNumber, Vec<(T::BlockNumber, BalanceOf<T>)>) {
+        let now = frame_system::Module::<T>::block_number();
+        let bucket_len = (window / OUTFLOW_BUCKETS.into()).max(One::one());
+        let current = now / bucket_len;
+        let count = window / bucket_len;
+        let buckets = Self::global_outflows(currency_id)
+            .into_iter()
+            .filter(|(bucket, _)| bucket.saturating_add(count) > current)
+            .collect::<Vec<_>>();
+        (current, buckets)
+    }
+
+    fn add_to_bucket(
+        buckets: &mut Vec<(T::BlockNumber, BalanceOf<T>)>,
+        current: T::BlockNumber,
+        value: BalanceOf<T>,
+    ) {
+        match buckets.last_mut() {
+            Some((bucket, total)) if *bucket == current => *total = total.saturating_add(value),
+            _ => buckets.push((current, value)),
+        }
+    }
+
+    /// Record the outflow checked by `check_withdraw_limits`, returns whether the withdrawal is
+    /// queued for review.
+    fn record_outflow(
+        withdraw_id: u64,
+        info: &WithdrawInfo<CurrencyIdOf<T>, T::AccountId, BalanceOf<T>>,
+        outflow: Outflow<T>,
+    ) -> bool {
+        let day = outflow.account.map(|(day, requested)| {
+            AccountOutflows::<T>::insert(info.currency_id, &info.who, (day, requested));
+            day
+        });
+        let bucket = outflow.global.map(|(buckets, current)| {
+            GlobalOutflows::<T>::insert(info.currency_id, buckets);
+            current
+        });
+        if day.is_some() || bucket.is_some() {
+            CountedOutflows::<T>::insert(withdraw_id, (day, bucket));
+        }
+        outflow.review
+    }
+
+    /// Count a withdrawal which passes the review in the rolling window, it's not checked
+    /// against the global cap again.
+    fn record_reviewed_outflow(
+        withdraw_id: u64,
+        info: &WithdrawInfo<CurrencyIdOf<T>, T::AccountId, BalanceOf<T>>,
+    ) {
+        let window = match Self::withdraw_limits(info.currency_id).and_then(|l| l.global_cap) {
+            Some((_, window)) => window,
+            None => return,
+        };
+        let (current, mut buckets) = Self::outflow_buckets(info.currency_id, window);
+        Self::add_to_bucket(&mut buckets, current, info.value);
+        GlobalOutflows::<T>::insert(info.currency_id, buckets);
+        CountedOutflows::<T>::mutate(withdraw_id, |counted| {
+            let day = counted.and_then(|(day, _)| day);
+            *counted = Some((day, Some(current)));
+        });
+    }
+
+    /// Release the outflow counted for a cancelled or rejected withdrawal, nothing is released
+    /// if the day or the bucket is over.
+    fn release_outflow(
+        withdraw_id: u64,
+        info: &WithdrawInfo<CurrencyIdOf<T>, T::AccountId, BalanceOf<T>>,
+    ) {
+        let (day, bucket) = match CountedOutflows::<T>::take(withdraw_id) {
+            Some(counted) => counted,
+            None => return,
+        };
+        if let Some(day) = day {
+            let (last_day, requested) = Self::account_outflow(info.currency_id, &info.who);
+            if last_day == day {
+                AccountOutflows::<T>::insert(
+                    info.currency_id,
+                    &info.who,
+                    (day, requested.saturating_sub(info.value)),
+                );
+            }
+        }
+        if let Some(bucket) = bucket {
+            let mut buckets = Self::global_outflows(info.currency_id);
+            if let Some((_, total)) = buckets.iter_mut().find(|(b, _)| *b == bucket) {
+                *total = total.saturating_sub(info.value);
+                GlobalOutflows::<T>::insert(info.currency_id, buckets);
+            }
+        }
     }
 
     fn withdraw_check(
         info: &WithdrawInfo<CurrencyIdOf<T>, T::AccountId, BalanceOf<T>>,
     ) -> DispatchResult {
-        // todo check by chain in future
         if info.addr.len() > 80 {
             Err(Error::<T>::InvalidWithdraw)?
         }
         if info.memo.len() > 80 {
             Err(Error::<T>::InvalidWithdraw)?
         }
+        if !T::AddressChecker::is_valid_address(info.currency_id, &info.addr) {
+            Err(Error::<T>::InvalidAddress)?
+        }
         Ok(())
     }
 }
@@ -529,6 +1124,7 @@ impl<T: Trait> Module<T> {
                             addr: info.addr,
                             memo: info.memo,
                             state,
+                            approvals: Self::withdraw_approvals(index),
                         },
                         Self::consumed_fee(index),
                     ),
@@ -537,6 +1133,21 @@ impl<T: Trait> Module<T> {
             .collect()
     }
 
+    /// The fee of a withdrawal of `value`, by the fee schedule of the asset or the flat
+    /// `WithdrawalFee`.
+    pub fn withdraw_fee_of(currency_id: CurrencyIdOf<T>, value: BalanceOf<T>) -> BalanceOf<T> {
+        match Self::withdraw_fee_schedule(currency_id) {
+            Some(schedule) => {
+                let fee = schedule
+                    .fixed
+                    .saturating_add(Permill::from_parts(schedule.bps.saturating_mul(100)) * value);
+                let fee = fee.max(schedule.min);
+                schedule.max.map_or(fee, |max| fee.min(max))
+            }
+            None => Self::withdrawal_fee(currency_id),
+        }
+    }
+
     pub fn pending_withdraw_list() -> BTreeMap<
         u64,
         (
@@ -21,11 +21,12 @@ pub mod constants {
     pub const ALICE: <TestRuntime as frame_system::Trait>::AccountId = 2;
     pub const BOB: <TestRuntime as frame_system::Trait>::AccountId = 3;
     pub const CHRIS: <TestRuntime as frame_system::Trait>::AccountId = 4;
+    pub const TREASURY: <TestRuntime as frame_system::Trait>::AccountId = 100;
 
     pub const BIG_STRING: &[u8; 81] =
         b"xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx
         ";
-    pub const ADDRESS: &[u8; 12] = b"some_address";
+    pub const ADDRESS: &[u8; 42] = b"0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
     pub const MEMO: &[u8; 9] = b"some_memo";
     pub const CUR1: CurrencyId = 1;
     pub const CUR2: CurrencyId = 2;
@@ -95,12 +96,12 @@ impl frame_system::Trait for TestRuntime {
     type PalletInfo = ();
     type OnNewAccount = ();
     type OnKilledAccount = ();
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<Balance>;
     type SystemWeightInfo = ();
 }
 
 parameter_types! {
-    pub const ExistentialDeposit: u64 = 0;
+    pub const ExistentialDeposit: Balance = 1;
     pub const TransferFee: u64 = 0;
     pub const CreationFee: u64 = 0;
     pub const TransactionBaseFee: u64 = 0;
@@ -123,18 +124,50 @@ impl timestamp::Trait for TestRuntime {
     type WeightInfo = ();
 }
 
+impl pallet_balances::Trait for TestRuntime {
+    type Balance = Balance;
+    type Event = ();
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type MaxLocks = ();
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const AssetDeposit: Balance = 0;
+    pub const UserAssetIdStart: CurrencyId = 10001;
+    pub const MaxDestroyAccounts: u32 = 100;
+    pub const MaxSnapshots: u32 = 10;
+}
+
 impl rio_assets::Trait for TestRuntime {
     type Event = ();
     type Balance = Balance;
     type Amount = Amount;
     type CurrencyId = CurrencyId;
     type OnReceived = ();
+    type OnDust = ();
+    type NativeCurrency = pallet_balances::Module<TestRuntime>;
+    type AssetDeposit = AssetDeposit;
+    type UserAssetIdStart = UserAssetIdStart;
+    type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxDestroyAccounts = MaxDestroyAccounts;
+    type MaxSnapshots = MaxSnapshots;
     type WeightInfo = ();
 }
 
+parameter_types! {
+    pub const BlocksPerDay: BlockNumber = 100;
+    pub const TreasuryAccount: AccountId = TREASURY;
+}
+
 impl Trait for TestRuntime {
     type Event = ();
     type Currency = RioAssets;
+    type BlocksPerDay = BlocksPerDay;
+    type TreasuryAccount = TreasuryAccount;
+    type AddressChecker = RioAssets;
     type WeightInfo = ();
 }
 
@@ -176,7 +209,8 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
                 name: b"CUR2 token".to_vec(),
                 decimals: 6,
                 desc: b"CUR1".to_vec(),
-                chain: rio_assets::Chain::Ethereum,
+                chain: rio_assets::Chain::Ethereum.id(),
+                min_balance: 0,
             },
             rio_assets::Restrictions::none(),
             vec![],
@@ -9,7 +9,9 @@ use sp_runtime::{
     DispatchError, Perbill,
 };
 
-use super::mock::{constants::*, ExtBuilder, Gateway, GatewayErr, Origin, RioAssets, System};
+use super::mock::{
+    constants::*, BlocksPerDay, ExtBuilder, Gateway, GatewayErr, Origin, RioAssets, System,
+};
 
 fn next_block() {
     System::set_block_number(System::block_number() + 1);
@@ -127,6 +129,295 @@ fn deposit_repeated_tx_fails() {
     });
 }
 
+#[test]
+fn set_deposit_threshold_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(
+            Gateway::set_deposit_threshold(Origin::signed(ALICE), CUR1, 2),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            Gateway::set_deposit_threshold(frame_system::RawOrigin::Root.into(), CUR2, 2),
+            GatewayErr::AssetNotSupported,
+        );
+        // ROOT and ALICE are the deposit admins
+        assert_noop!(
+            Gateway::set_deposit_threshold(frame_system::RawOrigin::Root.into(), CUR1, 0),
+            GatewayErr::InvalidThreshold,
+        );
+        assert_noop!(
+            Gateway::set_deposit_threshold(frame_system::RawOrigin::Root.into(), CUR1, 3),
+            GatewayErr::InvalidThreshold,
+        );
+        assert_ok!(Gateway::set_deposit_threshold(
+            frame_system::RawOrigin::Root.into(),
+            CUR1,
+            2
+        ));
+        assert_eq!(Gateway::deposit_threshold(CUR1), 2);
+    });
+}
+
+#[test]
+fn deposit_threshold_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let tx = TxHash::from(b"xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx");
+        assert_ok!(Gateway::set_auth(
+            frame_system::RawOrigin::Root.into(),
+            BOB,
+            Auth::Deposit.into(),
+            3,
+            1,
+            1
+        ));
+        assert_ok!(Gateway::set_deposit_threshold(
+            frame_system::RawOrigin::Root.into(),
+            CUR1,
+            2
+        ));
+
+        assert_ok!(Gateway::deposit(
+            Origin::signed(ALICE),
+            CHRIS,
+            CUR1,
+            tx,
+            CASUAL_TRANSFER
+        ));
+        assert_eq!(RioAssets::accounts(CHRIS, CUR1).free, 0);
+        assert_noop!(
+            Gateway::deposit(Origin::signed(ALICE), CHRIS, CUR1, tx, CASUAL_TRANSFER),
+            GatewayErr::AlreadyConfirmed,
+        );
+
+        // a conflicting confirmation is recorded but not counted for the former deposit
+        assert_ok!(Gateway::deposit(
+            Origin::signed(BOB),
+            BOB,
+            CUR1,
+            tx,
+            LARGE_TRANSFER
+        ));
+        assert_eq!(RioAssets::accounts(BOB, CUR1).free, 0);
+        assert_eq!(Gateway::deposit_confirmations(CUR1, tx).len(), 2);
+        assert!(Gateway::deposit_history(CUR1, tx).is_none());
+
+        assert_ok!(Gateway::deposit(
+            Origin::signed(ROOT),
+            CHRIS,
+            CUR1,
+            tx,
+            CASUAL_TRANSFER
+        ));
+        assert_eq!(RioAssets::accounts(CHRIS, CUR1).free, CASUAL_TRANSFER);
+        assert_eq!(RioAssets::accounts(BOB, CUR1).free, 0);
+        assert!(Gateway::deposit_confirmations(CUR1, tx).is_empty());
+        assert_eq!(
+            Gateway::deposit_history(CUR1, tx),
+            Some(Deposit {
+                account_id: CHRIS,
+                amount: CASUAL_TRANSFER,
+            })
+        );
+        assert_noop!(
+            Gateway::deposit(Origin::signed(BOB), BOB, CUR1, tx, LARGE_TRANSFER),
+            GatewayErr::TransactionRepeated,
+        );
+    });
+}
+
+#[test]
+fn revoke_deposit_admin_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let tx = TxHash::from(b"xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx");
+        assert_ok!(Gateway::set_auth(
+            frame_system::RawOrigin::Root.into(),
+            BOB,
+            Auth::Deposit.into(),
+            3,
+            1,
+            1
+        ));
+        assert_ok!(Gateway::set_deposit_threshold(
+            frame_system::RawOrigin::Root.into(),
+            CUR1,
+            3
+        ));
+        // the threshold would be unreachable
+        assert_noop!(
+            Gateway::set_auth(
+                frame_system::RawOrigin::Root.into(),
+                BOB,
+                Auth::Withdraw.into(),
+                3,
+                1,
+                1
+            ),
+            GatewayErr::InvalidThreshold,
+        );
+        assert_ok!(Gateway::set_deposit_threshold(
+            frame_system::RawOrigin::Root.into(),
+            CUR1,
+            2
+        ));
+
+        // the confirmation of a revoked admin is removed, it's not counted after regranted
+        assert_ok!(Gateway::deposit(
+            Origin::signed(BOB),
+            CHRIS,
+            CUR1,
+            tx,
+            CASUAL_TRANSFER
+        ));
+        // the witnesses must cover the admins, the thresholds and the pending deposits
+        assert_noop!(
+            Gateway::set_auth(
+                frame_system::RawOrigin::Root.into(),
+                BOB,
+                Auth::Withdraw.into(),
+                2,
+                1,
+                1
+            ),
+            GatewayErr::InvalidWitness,
+        );
+        assert_noop!(
+            Gateway::set_auth(
+                frame_system::RawOrigin::Root.into(),
+                BOB,
+                Auth::Withdraw.into(),
+                3,
+                0,
+                1
+            ),
+            GatewayErr::InvalidWitness,
+        );
+        assert_noop!(
+            Gateway::set_auth(
+                frame_system::RawOrigin::Root.into(),
+                BOB,
+                Auth::Withdraw.into(),
+                3,
+                1,
+                0
+            ),
+            GatewayErr::InvalidWitness,
+        );
+        assert_ok!(Gateway::set_auth(
+            frame_system::RawOrigin::Root.into(),
+            BOB,
+            Auth::Withdraw.into(),
+            3,
+            1,
+            1
+        ));
+        assert!(Gateway::deposit_confirmations(CUR1, tx).is_empty());
+        assert_ok!(Gateway::set_auth(
+            frame_system::RawOrigin::Root.into(),
+            BOB,
+            Auth::Deposit.into(),
+            3,
+            1,
+            1
+        ));
+        assert_ok!(Gateway::deposit(
+            Origin::signed(ALICE),
+            CHRIS,
+            CUR1,
+            tx,
+            CASUAL_TRANSFER
+        ));
+        assert_eq!(RioAssets::accounts(CHRIS, CUR1).free, 0);
+        assert_eq!(Gateway::deposit_confirmations(CUR1, tx).len(), 1);
+    });
+}
+
+#[test]
+fn revoke_withdraw_admin_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(Gateway::set_auth(
+            frame_system::RawOrigin::Root.into(),
+            BOB,
+            Auth::Withdraw.into(),
+            3,
+            1,
+            1
+        ));
+        // ROOT, ALICE and BOB are the withdraw admins
+        assert_ok!(Gateway::set_withdraw_quorum(
+            frame_system::RawOrigin::Root.into(),
+            CUR1,
+            Some(WithdrawQuorum {
+                threshold: LARGE_TRANSFER / 2,
+                approvals: 3,
+            })
+        ));
+        // the quorum would be unreachable
+        assert_noop!(
+            Gateway::set_auth(
+                frame_system::RawOrigin::Root.into(),
+                BOB,
+                Auth::Deposit.into(),
+                3,
+                1,
+                1
+            ),
+            GatewayErr::InvalidQuorum,
+        );
+
+        assert_ok!(Gateway::set_withdraw_quorum(
+            frame_system::RawOrigin::Root.into(),
+            CUR1,
+            Some(WithdrawQuorum {
+                threshold: LARGE_TRANSFER / 2,
+                approvals: 2,
+            })
+        ));
+        assert_ok!(Gateway::set_auth(
+            frame_system::RawOrigin::Root.into(),
+            BOB,
+            Auth::Deposit.into(),
+            3,
+            1,
+            1
+        ));
+        assert!(!Gateway::admins(BOB).contains(Auth::Withdraw));
+    });
+}
+
+#[test]
+fn remove_deposit_confirmations_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let tx = TxHash::from(b"xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx");
+        assert_ok!(Gateway::set_deposit_threshold(
+            frame_system::RawOrigin::Root.into(),
+            CUR1,
+            2
+        ));
+        assert_ok!(Gateway::deposit(
+            Origin::signed(ALICE),
+            CHRIS,
+            CUR1,
+            tx,
+            CASUAL_TRANSFER
+        ));
+
+        assert_eq!(
+            Gateway::remove_deposit_confirmations(Origin::signed(ALICE), CUR1, tx),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(Gateway::remove_deposit_confirmations(
+            frame_system::RawOrigin::Root.into(),
+            CUR1,
+            tx
+        ));
+        assert!(Gateway::deposit_confirmations(CUR1, tx).is_empty());
+        assert_noop!(
+            Gateway::remove_deposit_confirmations(frame_system::RawOrigin::Root.into(), CUR1, tx),
+            GatewayErr::DepositNotConfirmed,
+        );
+    });
+}
+
 #[test]
 fn create_withdraw_request_works() {
     ExtBuilder::default().build().execute_with(|| {
@@ -191,6 +482,46 @@ fn create_withdraw_request_len_fails() {
     });
 }
 
+#[test]
+fn create_withdraw_request_invalid_address_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        let deposit = TxHash::from(b"xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx");
+        assert_ok!(Gateway::deposit(
+            Origin::signed(ALICE),
+            ALICE,
+            CUR1,
+            deposit,
+            LARGE_TRANSFER
+        ));
+        // CUR1 is an asset from Ethereum
+        let invalid: &[&[u8]] = &[
+            b"some_address",
+            b"1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa",
+            // the EIP-55 checksum is broken
+            b"0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeaEd",
+        ];
+        for address in invalid {
+            assert_noop!(
+                Gateway::request_withdraw(
+                    Origin::signed(ALICE),
+                    CUR1,
+                    CASUAL_TRANSFER,
+                    address.to_vec(),
+                    MEMO.to_vec()
+                ),
+                GatewayErr::InvalidAddress,
+            );
+        }
+        assert_ok!(Gateway::request_withdraw(
+            Origin::signed(ALICE),
+            CUR1,
+            CASUAL_TRANSFER,
+            b"0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed".to_vec(),
+            MEMO.to_vec()
+        ));
+    });
+}
+
 #[test]
 fn approve_withdraw_request_works() {
     ExtBuilder::default().build().execute_with(|| {
@@ -218,6 +549,298 @@ fn approve_withdraw_request_works() {
     });
 }
 
+#[test]
+fn withdraw_quorum_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let deposit = TxHash::from(b"xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx");
+        assert_ok!(Gateway::deposit(
+            Origin::signed(ALICE),
+            ALICE,
+            CUR1,
+            deposit,
+            LARGE_TRANSFER
+        ));
+        let quorum = WithdrawQuorum {
+            threshold: LARGE_TRANSFER / 2,
+            approvals: 2,
+        };
+        // ROOT and ALICE are the withdraw admins
+        assert_noop!(
+            Gateway::set_withdraw_quorum(
+                frame_system::RawOrigin::Root.into(),
+                CUR1,
+                Some(WithdrawQuorum {
+                    approvals: 3,
+                    ..quorum
+                })
+            ),
+            GatewayErr::InvalidQuorum,
+        );
+        assert_ok!(Gateway::set_withdraw_quorum(
+            frame_system::RawOrigin::Root.into(),
+            CUR1,
+            Some(quorum)
+        ));
+
+        // a small withdrawal needs one approval
+        assert_ok!(Gateway::request_withdraw(
+            Origin::signed(ALICE),
+            CUR1,
+            CASUAL_TRANSFER,
+            ADDRESS.to_vec(),
+            MEMO.to_vec()
+        ));
+        let small_id = Gateway::next_withdrawal_id() - 1;
+        assert_ok!(Gateway::approve_withdraw(Origin::signed(ALICE), small_id));
+        assert_eq!(
+            Gateway::active_withdrawal_states(small_id),
+            Some(types::WithdrawState::Approved)
+        );
+
+        // a large withdrawal needs the quorum
+        assert_ok!(Gateway::request_withdraw(
+            Origin::signed(ALICE),
+            CUR1,
+            LARGE_TRANSFER / 2,
+            ADDRESS.to_vec(),
+            MEMO.to_vec()
+        ));
+        let large_id = Gateway::next_withdrawal_id() - 1;
+        assert_ok!(Gateway::approve_withdraw(Origin::signed(ALICE), large_id));
+        assert_eq!(
+            Gateway::active_withdrawal_states(large_id),
+            Some(types::WithdrawState::Pending)
+        );
+        assert_noop!(
+            Gateway::approve_withdraw(Origin::signed(ALICE), large_id),
+            GatewayErr::AlreadyApproved,
+        );
+        assert_eq!(
+            Gateway::withdraw_list()
+                .get(&large_id)
+                .map(|(item, _)| item.approvals.clone()),
+            Some(vec![ALICE])
+        );
+
+        assert_ok!(Gateway::approve_withdraw(Origin::signed(ROOT), large_id));
+        assert_eq!(
+            Gateway::active_withdrawal_states(large_id),
+            Some(types::WithdrawState::Approved)
+        );
+        assert_eq!(Gateway::withdraw_approvals(large_id), vec![ALICE, ROOT]);
+
+        assert_ok!(Gateway::finish_withdraw(
+            Origin::signed(ALICE),
+            large_id,
+            deposit
+        ));
+        assert!(Gateway::withdraw_approvals(large_id).is_empty());
+    });
+}
+
+#[test]
+fn withdraw_limits_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let withdraw = |who, value| {
+            Gateway::request_withdraw(
+                Origin::signed(who),
+                CUR1,
+                value,
+                ADDRESS.to_vec(),
+                MEMO.to_vec(),
+            )
+        };
+        assert_ok!(Gateway::deposit(
+            Origin::signed(ALICE),
+            ALICE,
+            CUR1,
+            TxHash::repeat_byte(1),
+            LARGE_TRANSFER
+        ));
+        assert_ok!(Gateway::deposit(
+            Origin::signed(ALICE),
+            BOB,
+            CUR1,
+            TxHash::repeat_byte(2),
+            LARGE_TRANSFER
+        ));
+
+        let limits = WithdrawLimits {
+            min: DECIMALS,
+            max: Some(100 * DECIMALS),
+            daily_cap: Some(150 * DECIMALS),
+            global_cap: Some((200 * DECIMALS, 10)),
+        };
+        assert_noop!(
+            Gateway::set_withdraw_limits(Origin::signed(BOB), CUR1, Some(limits)),
+            GatewayErr::UnAuthorized,
+        );
+        assert_noop!(
+            Gateway::set_withdraw_limits(
+                Origin::signed(ALICE),
+                CUR1,
+                Some(WithdrawLimits {
+                    max: Some(DECIMALS / 2),
+                    ..limits
+                })
+            ),
+            GatewayErr::InvalidWithdrawLimits,
+        );
+        assert_noop!(
+            Gateway::set_withdraw_limits(
+                Origin::signed(ALICE),
+                CUR1,
+                Some(WithdrawLimits {
+                    global_cap: Some((200 * DECIMALS, 0)),
+                    ..limits
+                })
+            ),
+            GatewayErr::InvalidWithdrawLimits,
+        );
+        assert_ok!(Gateway::set_withdraw_limits(
+            Origin::signed(ALICE),
+            CUR1,
+            Some(limits)
+        ));
+
+        assert_noop!(withdraw(ALICE, DECIMALS / 2), GatewayErr::WithdrawBelowMin);
+        assert_noop!(
+            withdraw(ALICE, 101 * DECIMALS),
+            GatewayErr::WithdrawAboveMax
+        );
+
+        assert_ok!(withdraw(ALICE, 100 * DECIMALS));
+        assert_noop!(withdraw(ALICE, 60 * DECIMALS), GatewayErr::ExceedDailyCap);
+        assert_ok!(withdraw(ALICE, 50 * DECIMALS));
+        let alice_id = Gateway::next_withdrawal_id() - 1;
+        assert_eq!(
+            Gateway::active_withdrawal_states(alice_id),
+            Some(types::WithdrawState::Pending)
+        );
+
+        // over the global cap
+        assert_ok!(withdraw(BOB, 60 * DECIMALS));
+        let bob_id = Gateway::next_withdrawal_id() - 1;
+        assert_eq!(
+            Gateway::active_withdrawal_states(bob_id),
+            Some(types::WithdrawState::Reviewing)
+        );
+        assert_noop!(
+            Gateway::approve_withdraw(Origin::signed(ALICE), bob_id),
+            GatewayErr::InvalidWithdrawalState,
+        );
+        assert_noop!(
+            Gateway::review_withdraw(Origin::signed(BOB), bob_id),
+            GatewayErr::UnAuthorized,
+        );
+        assert_noop!(
+            Gateway::review_withdraw(Origin::signed(ALICE), alice_id),
+            GatewayErr::InvalidWithdrawalState,
+        );
+        assert_ok!(Gateway::review_withdraw(Origin::signed(ALICE), bob_id));
+        assert_eq!(
+            Gateway::active_withdrawal_states(bob_id),
+            Some(types::WithdrawState::Pending)
+        );
+        assert_ok!(Gateway::approve_withdraw(Origin::signed(ALICE), bob_id));
+
+        // the daily cap and the window are reset
+        System::set_block_number(BlocksPerDay::get() + 1);
+        assert_ok!(withdraw(ALICE, 100 * DECIMALS));
+        assert_eq!(
+            Gateway::active_withdrawal_states(Gateway::next_withdrawal_id() - 1),
+            Some(types::WithdrawState::Pending)
+        );
+    });
+}
+
+#[test]
+fn withdraw_outflow_is_released_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let withdraw = |who, value| {
+            Gateway::request_withdraw(
+                Origin::signed(who),
+                CUR1,
+                value,
+                ADDRESS.to_vec(),
+                MEMO.to_vec(),
+            )
+        };
+        let global_outflow = || {
+            Gateway::global_outflows(CUR1)
+                .iter()
+                .fold(0, |total, (_, value)| total + value)
+        };
+        assert_ok!(Gateway::deposit(
+            Origin::signed(ALICE),
+            ALICE,
+            CUR1,
+            TxHash::repeat_byte(1),
+            LARGE_TRANSFER
+        ));
+        assert_ok!(Gateway::deposit(
+            Origin::signed(ALICE),
+            BOB,
+            CUR1,
+            TxHash::repeat_byte(2),
+            LARGE_TRANSFER
+        ));
+
+        // nothing is recorded without limits
+        assert_ok!(withdraw(ALICE, 100 * DECIMALS));
+        let id = Gateway::next_withdrawal_id() - 1;
+        assert_eq!(Gateway::account_outflow(CUR1, ALICE), (0, 0));
+        assert_eq!(Gateway::counted_outflow(id), None);
+        assert_ok!(Gateway::cancel_withdraw(Origin::signed(ALICE), id));
+
+        assert_ok!(Gateway::set_withdraw_limits(
+            Origin::signed(ALICE),
+            CUR1,
+            Some(WithdrawLimits {
+                min: DECIMALS,
+                max: Some(100 * DECIMALS),
+                daily_cap: Some(150 * DECIMALS),
+                global_cap: Some((200 * DECIMALS, 10)),
+            })
+        ));
+
+        // the cancelled and rejected withdrawals are not counted in the caps
+        assert_ok!(withdraw(ALICE, 100 * DECIMALS));
+        let id = Gateway::next_withdrawal_id() - 1;
+        assert_noop!(withdraw(ALICE, 100 * DECIMALS), GatewayErr::ExceedDailyCap);
+        assert_ok!(Gateway::cancel_withdraw(Origin::signed(ALICE), id));
+        assert_eq!(Gateway::account_outflow(CUR1, ALICE).1, 0);
+        assert_eq!(global_outflow(), 0);
+        assert_eq!(Gateway::counted_outflow(id), None);
+
+        assert_ok!(withdraw(ALICE, 100 * DECIMALS));
+        let id = Gateway::next_withdrawal_id() - 1;
+        assert_ok!(Gateway::reject_withdraw(Origin::signed(ALICE), id));
+        assert_eq!(Gateway::account_outflow(CUR1, ALICE).1, 0);
+        assert_eq!(global_outflow(), 0);
+
+        assert_ok!(withdraw(ALICE, 100 * DECIMALS));
+        assert_eq!(Gateway::account_outflow(CUR1, ALICE).1, 100 * DECIMALS);
+        assert_eq!(global_outflow(), 100 * DECIMALS);
+
+        // counted in the window once reviewed
+        assert_ok!(withdraw(BOB, 100 * DECIMALS));
+        assert_ok!(withdraw(BOB, 50 * DECIMALS));
+        let bob_id = Gateway::next_withdrawal_id() - 1;
+        assert_eq!(
+            Gateway::active_withdrawal_states(bob_id),
+            Some(types::WithdrawState::Reviewing)
+        );
+        assert_eq!(global_outflow(), 200 * DECIMALS);
+        assert_ok!(Gateway::review_withdraw(Origin::signed(ALICE), bob_id));
+        assert_eq!(global_outflow(), 250 * DECIMALS);
+
+        assert_ok!(Gateway::reject_withdraw(Origin::signed(ALICE), bob_id));
+        assert_eq!(Gateway::account_outflow(CUR1, BOB).1, 100 * DECIMALS);
+        assert_eq!(global_outflow(), 200 * DECIMALS);
+    });
+}
+
 #[test]
 fn reject_withdraw_request_works() {
     ExtBuilder::default().build().execute_with(|| {
@@ -274,6 +897,142 @@ fn finish_withdraw_request_works() {
     });
 }
 
+#[test]
+fn withdraw_fee_schedule_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let finish = |value, hash| {
+            assert_ok!(Gateway::request_withdraw(
+                Origin::signed(ALICE),
+                CUR1,
+                value,
+                ADDRESS.to_vec(),
+                MEMO.to_vec()
+            ));
+            let curr_id = Gateway::next_withdrawal_id() - 1;
+            assert_ok!(Gateway::approve_withdraw(Origin::signed(ALICE), curr_id));
+            assert_ok!(Gateway::finish_withdraw(
+                Origin::signed(ALICE),
+                curr_id,
+                hash
+            ));
+        };
+        assert_ok!(Gateway::deposit(
+            Origin::signed(ALICE),
+            ALICE,
+            CUR1,
+            TxHash::repeat_byte(1),
+            LARGE_TRANSFER
+        ));
+
+        let schedule = WithdrawFeeSchedule {
+            fixed: DECIMALS / 100,
+            bps: 100, // 1%
+            min: DECIMALS / 10,
+            max: Some(DECIMALS),
+        };
+        assert_noop!(
+            Gateway::set_withdraw_fee_schedule(Origin::signed(BOB), CUR1, Some(schedule)),
+            GatewayErr::UnAuthorized,
+        );
+        assert_noop!(
+            Gateway::set_withdraw_fee_schedule(
+                Origin::signed(ALICE),
+                CUR1,
+                Some(WithdrawFeeSchedule {
+                    bps: 10_001,
+                    ..schedule
+                })
+            ),
+            GatewayErr::InvalidFeeSchedule,
+        );
+        assert_noop!(
+            Gateway::set_withdraw_fee_schedule(
+                Origin::signed(ALICE),
+                CUR1,
+                Some(WithdrawFeeSchedule {
+                    max: Some(DECIMALS / 100),
+                    ..schedule
+                })
+            ),
+            GatewayErr::InvalidFeeSchedule,
+        );
+        assert_ok!(Gateway::set_withdraw_fee_schedule(
+            Origin::signed(ALICE),
+            CUR1,
+            Some(schedule)
+        ));
+
+        // the min, the percentage and the max of the schedule
+        assert_eq!(Gateway::withdraw_fee_of(CUR1, DECIMALS), DECIMALS / 10);
+        assert_eq!(
+            Gateway::withdraw_fee_of(CUR1, 50 * DECIMALS),
+            DECIMALS / 100 + DECIMALS / 2
+        );
+        assert_eq!(Gateway::withdraw_fee_of(CUR1, 1000 * DECIMALS), DECIMALS);
+
+        // the fee goes to the treasury by default
+        let fee = Gateway::withdraw_fee_of(CUR1, 50 * DECIMALS);
+        finish(50 * DECIMALS, TxHash::repeat_byte(2));
+        assert_eq!(
+            RioAssets::accounts(ALICE, CUR1).free,
+            LARGE_TRANSFER - 50 * DECIMALS - fee
+        );
+        assert_eq!(RioAssets::accounts(ALICE, CUR1).reserved, 0);
+        assert_eq!(RioAssets::accounts(TREASURY, CUR1).free, fee);
+
+        // and to the fee account once set
+        assert_noop!(
+            Gateway::set_withdraw_fee_account(Origin::signed(ALICE), Some(CHRIS)),
+            DispatchError::BadOrigin,
+        );
+        assert_ok!(Gateway::set_withdraw_fee_account(
+            Origin::root(),
+            Some(CHRIS)
+        ));
+        finish(1000 * DECIMALS, TxHash::repeat_byte(3));
+        assert_eq!(RioAssets::accounts(CHRIS, CUR1).free, DECIMALS);
+        assert_eq!(RioAssets::accounts(TREASURY, CUR1).free, fee);
+
+        assert_ok!(Gateway::set_withdraw_fee_account(Origin::root(), None));
+        assert_eq!(Gateway::withdraw_fee_account(), None);
+        assert_ok!(Gateway::set_withdraw_fee_schedule(
+            Origin::signed(ALICE),
+            CUR1,
+            None
+        ));
+        assert_eq!(
+            Gateway::withdraw_fee_of(CUR1, DECIMALS),
+            Gateway::withdrawal_fee(CUR1)
+        );
+    });
+}
+
+#[test]
+fn withdraw_value_with_fee_overflow_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(Gateway::set_withdraw_fee_schedule(
+            Origin::signed(ALICE),
+            CUR1,
+            Some(WithdrawFeeSchedule {
+                fixed: DECIMALS / 100,
+                bps: 0,
+                min: 0,
+                max: None,
+            })
+        ));
+        assert_noop!(
+            Gateway::request_withdraw(
+                Origin::signed(ALICE),
+                CUR1,
+                u128::MAX,
+                ADDRESS.to_vec(),
+                MEMO.to_vec()
+            ),
+            GatewayErr::WithdrawOverflow,
+        );
+    });
+}
+
 #[test]
 fn cancel_withdraw_request_works() {
     ExtBuilder::default().build().execute_with(|| {
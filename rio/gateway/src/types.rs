@@ -61,6 +61,8 @@ pub enum WithdrawState {
     Approved,
     Success(TxHash),
     ReBroadcasted(TxHash),
+    /// over the global outflow cap, waiting to be reviewed by admin
+    Reviewing,
 }
 
 #[derive(Encode, Decode, PartialEq, Eq, Clone, RuntimeDebug)]
@@ -71,12 +73,91 @@ pub struct WithdrawItem<CurrencyId, AccountId, Balance> {
     pub addr: ChainAddress,
     pub memo: Memo,
     pub state: WithdrawState,
+    /// the admins who approved the withdrawal
+    pub approvals: Vec<AccountId>,
+}
+
+/// `WithdrawItem` of the version 1 of `GatewayApi`, only used to query the runtimes before
+/// the approvals.
+#[derive(Encode, Decode, PartialEq, Eq, Clone, RuntimeDebug)]
+pub struct WithdrawItemV1<CurrencyId, AccountId, Balance> {
+    pub currency_id: CurrencyId,
+    pub applicant: AccountId,
+    pub value: Balance,
+    pub addr: ChainAddress,
+    pub memo: Memo,
+    pub state: WithdrawState,
+}
+
+impl<CurrencyId, AccountId, Balance> From<WithdrawItemV1<CurrencyId, AccountId, Balance>>
+    for WithdrawItem<CurrencyId, AccountId, Balance>
+{
+    fn from(item: WithdrawItemV1<CurrencyId, AccountId, Balance>) -> Self {
+        WithdrawItem {
+            currency_id: item.currency_id,
+            applicant: item.applicant,
+            value: item.value,
+            addr: item.addr,
+            memo: item.memo,
+            state: item.state,
+            approvals: Vec::new(),
+        }
+    }
+}
+
+/// The withdrawals of an asset whose value is not less than `threshold` need `approvals` distinct
+/// admins to be approved, the others need one.
+#[derive(Encode, Decode, PartialEq, Eq, Clone, Copy, RuntimeDebug)]
+pub struct WithdrawQuorum<Balance> {
+    pub threshold: Balance,
+    pub approvals: u32,
+}
+
+/// The fee of a withdrawal is `fixed + value * bps / 10_000`, bounded by `min` and `max`.
+#[derive(Encode, Decode, PartialEq, Eq, Clone, Copy, RuntimeDebug)]
+pub struct WithdrawFeeSchedule<Balance> {
+    pub fixed: Balance,
+    /// basis points of the value, no more than 10_000
+    pub bps: u32,
+    pub min: Balance,
+    /// no max if it's `None`
+    pub max: Option<Balance>,
+}
+
+/// The limits of the withdrawals of an asset.
+#[derive(Encode, Decode, PartialEq, Eq, Clone, Copy, RuntimeDebug)]
+pub struct WithdrawLimits<Balance, BlockNumber> {
+    /// the min value of a withdrawal
+    pub min: Balance,
+    /// the max value of a withdrawal, no max if it's `None`
+    pub max: Option<Balance>,
+    /// the total value an account could request in a day, no cap if it's `None`
+    pub daily_cap: Option<Balance>,
+    /// `(cap, window)`, the withdrawals over the total value requested in the last `window`
+    /// blocks are queued for review, no cap if it's `None`
+    pub global_cap: Option<(Balance, BlockNumber)>,
+}
+
+/// Storage version of the module.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Releases {
+    /// Original version of the module.
+    V0,
+    /// The approvals of the withdrawals are recorded in `WithdrawApprovals`.
+    V1,
+}
+
+impl Default for Releases {
+    fn default() -> Self {
+        Releases::V0
+    }
 }
 
 #[derive(RuntimeDebug)]
 pub enum WithdrawPhase {
     First,
     Second,
+    Review,
 }
 
 #[derive(Encode, Decode, PartialEq, Eq, Clone, RuntimeDebug)]
@@ -4,7 +4,7 @@ use frame_support::weights::{
 };
 
 pub trait WeightInfo {
-    fn set_auth() -> Weight;
+    fn set_auth(a: u32, t: u32, c: u32) -> Weight;
     fn register_asset() -> Weight;
     fn remove_asset() -> Weight;
     fn set_bip32_info() -> Weight;
@@ -19,13 +19,33 @@ pub trait WeightInfo {
     fn withdraw_finish() -> Weight;
     fn rebroadcast() -> Weight;
     fn modify_withdraw_state() -> Weight;
+    fn set_deposit_threshold() -> Weight;
+    fn set_withdraw_quorum() -> Weight;
+    fn set_withdraw_limits() -> Weight;
+    fn review_withdraw() -> Weight;
+    fn set_withdraw_fee_schedule() -> Weight;
+    fn set_withdraw_fee_account() -> Weight;
+    fn remove_deposit_confirmations() -> Weight;
 }
 
 impl WeightInfo for () {
-    fn set_auth() -> Weight {
+    fn set_auth(a: u32, t: u32, c: u32) -> Weight {
         WEIGHT_PER_MICROS
             .saturating_mul(80)
-            .saturating_add(DbWeight::get().reads_writes(2, 2))
+            .saturating_add(
+                WEIGHT_PER_MICROS
+                    .saturating_mul(5)
+                    .saturating_mul(a as Weight + 2 * t as Weight),
+            )
+            .saturating_add(
+                WEIGHT_PER_MICROS
+                    .saturating_mul(10)
+                    .saturating_mul(c as Weight),
+            )
+            .saturating_add(DbWeight::get().reads_writes(
+                1 + 2 * a as Weight + 2 * t as Weight + c as Weight,
+                1 + c as Weight,
+            ))
     }
 
     fn register_asset() -> Weight {
@@ -60,33 +80,33 @@ impl WeightInfo for () {
     }
     fn deposit() -> Weight {
         WEIGHT_PER_MICROS
-            .saturating_mul(80)
-            .saturating_add(DbWeight::get().reads_writes(2, 2))
+            .saturating_mul(100)
+            .saturating_add(DbWeight::get().reads_writes(5, 3))
     }
     fn withdraw() -> Weight {
         WEIGHT_PER_MICROS
-            .saturating_mul(80)
-            .saturating_add(DbWeight::get().reads_writes(2, 2))
+            .saturating_mul(120)
+            .saturating_add(DbWeight::get().reads_writes(6, 7))
     }
     fn revoke_withdraw() -> Weight {
         WEIGHT_PER_MICROS
             .saturating_mul(81)
-            .saturating_add(DbWeight::get().reads_writes(2, 2))
+            .saturating_add(DbWeight::get().reads_writes(5, 5))
     }
     fn reject_withdraw() -> Weight {
         WEIGHT_PER_MICROS
             .saturating_mul(81)
-            .saturating_add(DbWeight::get().reads_writes(2, 2))
+            .saturating_add(DbWeight::get().reads_writes(5, 5))
     }
     fn approve_withdraw() -> Weight {
         WEIGHT_PER_MICROS
-            .saturating_mul(81)
-            .saturating_add(DbWeight::get().reads_writes(2, 2))
+            .saturating_mul(100)
+            .saturating_add(DbWeight::get().reads_writes(6, 2))
     }
     fn withdraw_finish() -> Weight {
         WEIGHT_PER_MICROS
-            .saturating_mul(50)
-            .saturating_add(DbWeight::get().reads_writes(2, 2))
+            .saturating_mul(60)
+            .saturating_add(DbWeight::get().reads_writes(4, 3))
     }
     fn rebroadcast() -> Weight {
         WEIGHT_PER_MICROS
@@ -98,4 +118,39 @@ impl WeightInfo for () {
             .saturating_mul(81)
             .saturating_add(DbWeight::get().reads_writes(2, 2))
     }
+    fn set_deposit_threshold() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(60)
+            .saturating_add(DbWeight::get().reads_writes(10, 1))
+    }
+    fn set_withdraw_quorum() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(60)
+            .saturating_add(DbWeight::get().reads_writes(10, 1))
+    }
+    fn set_withdraw_limits() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(60)
+            .saturating_add(DbWeight::get().reads_writes(2, 1))
+    }
+    fn review_withdraw() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(81)
+            .saturating_add(DbWeight::get().reads_writes(4, 3))
+    }
+    fn set_withdraw_fee_schedule() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(60)
+            .saturating_add(DbWeight::get().reads_writes(2, 1))
+    }
+    fn set_withdraw_fee_account() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(30)
+            .saturating_add(DbWeight::get().reads_writes(0, 1))
+    }
+    fn remove_deposit_confirmations() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(30)
+            .saturating_add(DbWeight::get().reads_writes(1, 1))
+    }
 }
@@ -0,0 +1,21 @@
+//! Benchmarks for the rio-payment-fee module.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+benchmarks! {
+    _ { }
+
+    set_account {
+        let who: T::AccountId = account("who", 0, SEED);
+    }: _(RawOrigin::Root, T::Lookup::unlookup(who.clone()))
+    verify {
+        assert_eq!(Module::<T>::account_id(), who);
+    }
+}
@@ -4,6 +4,7 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+mod benchmarking;
 mod weight_info;
 
 use frame_support::{
@@ -19,7 +20,7 @@ use rio_support::debug;
 
 use orml_traits::{MultiCurrency, MultiReservableCurrency};
 
-use weight_info::WeightInfo;
+pub use weight_info::WeightInfo;
 
 type BalanceOf<T> =
     <<T as Trait>::Currency as Currency<<T as frame_system::Trait>::AccountId>>::Balance;
@@ -114,8 +115,12 @@ impl<T: Trait> BuyFeeAsset for Module<T> {
 
     fn refund_fee_asset(who: &Self::AccountId, amount: Self::Balance) -> Result<(), DispatchError> {
         let locked_currency = rio_protocol::LOCKED_RFUEL.into();
+        let free = T::MultiCurrency::free_balance(locked_currency, who);
         T::MultiCurrency::deposit(locked_currency, who, amount)
             .expect("deposit remained balance must success");
-        T::MultiCurrency::reserve(locked_currency, who, amount)
+        // the `OnReceived` hook of the currency may have reserved it already, only the part
+        // left free is reserved, not the free balance `who` held before
+        let unreserved = T::MultiCurrency::free_balance(locked_currency, who).saturating_sub(free);
+        T::MultiCurrency::reserve(locked_currency, who, unreserved)
     }
 }
@@ -779,12 +779,30 @@ mod tests {
         static WEIGHT_TO_FEE: RefCell<u64> = RefCell::new(1);
     }
 
+    parameter_types! {
+        pub const AssetDeposit: u64 = 100;
+        pub const UserAssetIdStart: u32 = rio_protocol::USER_ASSET_START;
+        pub const MaxDestroyAccounts: u32 = 100;
+        pub const MaxSnapshots: u32 = 10;
+        pub const LockedRFuelId: u32 = rio_protocol::LOCKED_RFUEL;
+    }
+
     impl rio_assets::Trait for Runtime {
         type Event = Event;
         type Balance = u64;
         type Amount = i64;
         type CurrencyId = u32;
-        type OnReceived = ();
+        // the same as the runtime, the received locked rfuel is reserved
+        type OnReceived = rio_assets::ReceivedHandlers<(
+            rio_assets::ForCurrency<LockedRFuelId, rio_assets::ReserveReceived<Runtime>>,
+        )>;
+        type OnDust = ();
+        type NativeCurrency = Balances;
+        type AssetDeposit = AssetDeposit;
+        type UserAssetIdStart = UserAssetIdStart;
+        type ManagerOrigin = frame_system::EnsureRoot<u64>;
+        type MaxDestroyAccounts = MaxDestroyAccounts;
+        type MaxSnapshots = MaxSnapshots;
         type WeightInfo = ();
     }
     impl rio_payment_fee::Trait for Runtime {
@@ -900,7 +918,8 @@ mod tests {
                     name: b"Locked RioFuel".to_vec(),
                     decimals: 12,
                     desc: b"RioChain Locked RioFuel Token".to_vec(),
-                    chain: rio_assets::Chain::Rio,
+                    chain: rio_assets::Chain::Rio.id(),
+                    min_balance: 0,
                 },
                 rio_assets::Restriction::Transferable.into(),
                 vec![],
@@ -1435,8 +1454,8 @@ mod tests {
             .base_weight(5)
             .build()
             .execute_with(|| {
+                // reserved by the `OnReceived` hook
                 RioAssets::deposit(rio_protocol::LOCKED_RFUEL, &1, 30).unwrap();
-                RioAssets::reserve(rio_protocol::LOCKED_RFUEL, &1, 30).unwrap();
                 assert_eq!(
                     RioAssets::reserved_balance(rio_protocol::LOCKED_RFUEL, &1),
                     30
@@ -1498,8 +1517,8 @@ mod tests {
             .base_weight(5)
             .build()
             .execute_with(|| {
+                // reserved by the `OnReceived` hook
                 RioAssets::deposit(rio_protocol::LOCKED_RFUEL, &2, 200).unwrap();
-                RioAssets::reserve(rio_protocol::LOCKED_RFUEL, &2, 200).unwrap();
                 assert_eq!(
                     RioAssets::reserved_balance(rio_protocol::LOCKED_RFUEL, &2),
                     200
@@ -1592,4 +1611,50 @@ mod tests {
                 );
             });
     }
+
+    #[test]
+    fn refund_keeps_unreserved_locked_rfuel_free() {
+        ExtBuilder::default()
+            .balance_factor(10)
+            .base_weight(5)
+            .build()
+            .execute_with(|| {
+                RioAssets::deposit(rio_protocol::LOCKED_RFUEL, &2, 200).unwrap();
+                // unreserve is not a receipt, it stays free
+                assert_eq!(RioAssets::unreserve(rio_protocol::LOCKED_RFUEL, &2, 20), 0);
+                assert_eq!(RioAssets::free_balance(rio_protocol::LOCKED_RFUEL, &2), 20);
+                assert_eq!(
+                    RioAssets::reserved_balance(rio_protocol::LOCKED_RFUEL, &2),
+                    180
+                );
+
+                let len = 10;
+                NextFeeMultiplier::put(Multiplier::saturating_from_rational(3, 2));
+
+                let pre = ChargeTransactionPayment::<Runtime>::from(5 /* tipped */, None)
+                    .pre_dispatch(&2, CALL, &info_from_weight(100), len)
+                    .unwrap();
+                // 5 base fee, 10 byte fee, 3/2 * 100 weight fee, 5 tip
+                assert_eq!(
+                    RioAssets::reserved_balance(rio_protocol::LOCKED_RFUEL, &2),
+                    180 - 5 - 10 - 150 - 5
+                );
+
+                assert!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+                    pre,
+                    &info_from_weight(100),
+                    &post_info_from_weight(50),
+                    len,
+                    &Ok(())
+                )
+                .is_ok());
+                // 75 (3/2 of the returned 50 units of weight) is refunded and reserved again
+                assert_eq!(Balances::free_balance(2), 200);
+                assert_eq!(RioAssets::free_balance(rio_protocol::LOCKED_RFUEL, &2), 20);
+                assert_eq!(
+                    RioAssets::reserved_balance(rio_protocol::LOCKED_RFUEL, &2),
+                    180 - 5 - 10 - 75 - 5
+                );
+            });
+    }
 }
@@ -9,6 +9,9 @@ pub mod assets_def {
     pub const RLTC: u32 = 101;
     pub const RETH: u32 = 103;
     pub const RUSDT: u32 = 102;
+
+    /// user tokens start from here, see `docs/RST-88.md`
+    pub const USER_ASSET_START: u32 = 10001;
 }
 
 pub use assets_def::*;
@@ -18,4 +21,21 @@ pub const ASSET_SYMBOL_LEN: usize = 24;
 pub const ASSET_NAME_LEN: usize = 48;
 pub const ASSET_DESC_LEN: usize = 128;
 
+// chains
+pub const CHAIN_NAME_LEN: usize = 32;
+
 pub const MEMO_BYTES_LEN: usize = 80;
+
+// max legs in one batch transfer
+pub const MAX_BATCH_TRANSFER: usize = 256;
+
+// locks
+pub const LOCK_REASON_LEN: usize = 64;
+// max expired locks removed in one block
+pub const MAX_LOCK_EXPIRIES_PER_BLOCK: usize = 64;
+
+// pauses
+pub const PAUSE_REASON_LEN: usize = 128;
+
+// max vesting schedules of an account for an asset
+pub const MAX_VESTING_SCHEDULES: usize = 32;
@@ -0,0 +1,70 @@
+//! Benchmarks for the rio-root module.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::UnfilteredDispatchable;
+
+const SEED: u32 = 0;
+
+benchmarks! {
+    _ { }
+
+    modify_manager {
+        let who: T::AccountId = account("who", 0, SEED);
+        let origin = T::RootOrigin::successful_origin();
+        let call = Call::<T>::modify_manager(who.clone(), true);
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert!(Module::<T>::managers().contains(&who));
+    }
+
+    issue_locked_fee {
+        let who: T::AccountId = account("who", 0, SEED);
+        let amount: BalanceOf<T> = 1000u32.into();
+        let origin = T::RootOrigin::successful_origin();
+        let call = Call::<T>::issue_locked_gas_token(T::Lookup::unlookup(who.clone()), amount);
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert_eq!(T::Currency::reserved_balance(rio_protocol::LOCKED_RFUEL.into(), &who), amount);
+    }
+
+    toggle_blacklist {
+        let who: T::AccountId = account("who", 0, SEED);
+        let origin = T::RootOrigin::successful_origin();
+        let call = Call::<T>::toggle_blacklist(T::Lookup::unlookup(who.clone()), true);
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert!(Module::<T>::blacklist(&who));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{ExtBuilder, TestRuntime};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn modify_manager() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_modify_manager::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn issue_locked_fee() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_issue_locked_fee::<TestRuntime>());
+        });
+    }
+
+    #[test]
+    fn toggle_blacklist() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(test_benchmark_toggle_blacklist::<TestRuntime>());
+        });
+    }
+}
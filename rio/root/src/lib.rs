@@ -1,15 +1,21 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+mod benchmarking;
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod tests;
 mod weight_info;
 
-use sp_runtime::{traits::StaticLookup, DispatchResult};
+use sp_runtime::{
+    traits::{Saturating, StaticLookup},
+    DispatchResult,
+};
 use sp_std::prelude::*;
 
-use frame_support::{decl_error, decl_event, decl_module, decl_storage, traits::EnsureOrigin};
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage, traits::EnsureOrigin, transactional,
+};
 
 use orml_traits::{MultiCurrency, MultiReservableCurrency};
 
@@ -17,7 +23,7 @@ use rio_primitives::CurrencyId;
 use rio_support::debug;
 
 use frame_support::traits::Contains;
-use weight_info::WeightInfo;
+pub use weight_info::WeightInfo;
 
 pub trait Trait: frame_system::Trait {
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
@@ -82,14 +88,19 @@ decl_module! {
         }
 
         #[weight = T::WeightInfo::issue_locked_fee()]
+        #[transactional]
         pub fn issue_locked_gas_token(origin, to: <T::Lookup as StaticLookup>::Source, #[compact] amount: BalanceOf<T>) -> DispatchResult {
             T::RootOrigin::ensure_origin(origin)?;
 
             let to = T::Lookup::lookup(to)?;
             let currency_id = rio_protocol::LOCKED_RFUEL.into();
 
+            let free = T::Currency::free_balance(currency_id, &to);
             T::Currency::deposit(currency_id, &to, amount)?;
-            T::Currency::reserve(currency_id, &to, amount).expect("must success after deposit locked rfuel; qed");
+            // the `OnReceived` hook of the currency may have reserved it already, only the part
+            // left free is reserved, not the free balance `to` held before
+            let unreserved = T::Currency::free_balance(currency_id, &to).saturating_sub(free);
+            T::Currency::reserve(currency_id, &to, unreserved)?;
 
             debug!("[issue_locked_gas_token]|who:{:?}|amount:{:?}", to, amount);
 
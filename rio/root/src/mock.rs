@@ -85,30 +85,55 @@ impl frame_system::Trait for TestRuntime {
     type PalletInfo = ();
     type OnNewAccount = ();
     type OnKilledAccount = ();
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<Balance>;
     type SystemWeightInfo = ();
 }
 
 parameter_types! {
-    pub const ExistentialDeposit: u64 = 0;
+    pub const ExistentialDeposit: Balance = 1;
     pub const TransferFee: u64 = 0;
     pub const CreationFee: u64 = 0;
     pub const TransactionBaseFee: u64 = 0;
     pub const TransactionByteFee: u64 = 0;
 }
 
+impl pallet_balances::Trait for TestRuntime {
+    type Balance = Balance;
+    type Event = ();
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type MaxLocks = ();
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const AssetDeposit: Balance = 0;
+    pub const UserAssetIdStart: CurrencyId = 10001;
+    pub const MaxDestroyAccounts: u32 = 100;
+    pub const MaxSnapshots: u32 = 10;
+}
+
 impl rio_assets::Trait for TestRuntime {
     type Event = ();
     type Balance = Balance;
     type Amount = Amount;
     type CurrencyId = CurrencyId;
     type OnReceived = ();
+    type OnDust = ();
+    type NativeCurrency = pallet_balances::Module<TestRuntime>;
+    type AssetDeposit = AssetDeposit;
+    type UserAssetIdStart = UserAssetIdStart;
+    type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxDestroyAccounts = MaxDestroyAccounts;
+    type MaxSnapshots = MaxSnapshots;
     type WeightInfo = ();
 }
 
 impl Trait for TestRuntime {
     type Event = ();
     type Currency = RioAssets;
+    type RootOrigin = frame_system::EnsureRoot<AccountId>;
     type WeightInfo = ();
 }
 
@@ -145,7 +170,8 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
                 name: b"Locked RFuel Token".to_vec(),
                 decimals: 12,
                 desc: b"RioChain locked token".to_vec(),
-                chain: rio_assets::Chain::Rio,
+                chain: rio_assets::Chain::Rio.id(),
+                min_balance: 0,
             },
             rio_assets::Restriction::Transferable.into(),
             vec![],
@@ -6,3 +6,16 @@ pub trait PriceProvider<CurrencyId> {
     fn lock_price(currency_id: CurrencyId);
     fn unlock_price(currency_id: CurrencyId);
 }
+
+/// Check the addresses on the origin chain of an asset.
+pub trait AddressChecker<CurrencyId> {
+    /// Whether `address` is a valid address on the origin chain of `currency_id`.
+    fn is_valid_address(currency_id: CurrencyId, address: &[u8]) -> bool;
+}
+
+/// Accept all addresses.
+impl<CurrencyId> AddressChecker<CurrencyId> for () {
+    fn is_valid_address(_: CurrencyId, _: &[u8]) -> bool {
+        true
+    }
+}
@@ -0,0 +1,100 @@
+//! Benchmarks for the rio-vesting module.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::UnfilteredDispatchable;
+use frame_system::RawOrigin;
+use sp_std::vec;
+
+const SEED: u32 = 0;
+const MAX_SCHEDULES: u32 = rio_protocol::MAX_VESTING_SCHEDULES as u32;
+
+/// `RFUEL` of the genesis, the benchmarks could not create a currency id
+fn benchmark_currency<T: Trait>() -> CurrencyIdOf<T> {
+    CurrencyIdOf::<T>::decode(&mut &rio_protocol::RFUEL.encode()[..])
+        .expect("the currency id is encoded as u32")
+}
+
+/// a schedule of `MinVestedTransfer` which is still locked at the genesis
+fn benchmark_schedule<T: Trait>() -> VestingScheduleOf<T> {
+    VestingSchedule {
+        start: Zero::zero(),
+        period: 10u32.into(),
+        period_count: 10,
+        per_period: T::MinVestedTransfer::get(),
+    }
+}
+
+/// deposit the balance of `count` schedules to `who` and lock it by the schedules
+fn add_schedules<T: Trait>(
+    who: &T::AccountId,
+    currency_id: CurrencyIdOf<T>,
+    count: u32,
+) -> Result<(), &'static str> {
+    let schedule = benchmark_schedule::<T>();
+    let amount = schedule
+        .total_amount()
+        .ok_or("schedule amount overflows")?
+        .saturating_mul(count.into());
+    T::Currency::deposit(currency_id, who, amount)?;
+    if count > 0 {
+        T::Currency::set_lock(VESTING_LOCK_ID, currency_id, who, amount);
+        VestingSchedules::<T>::insert(who, currency_id, vec![schedule; count as usize]);
+    }
+    Ok(())
+}
+
+benchmarks! {
+    _ { }
+
+    claim {
+        let who: T::AccountId = account("who", 0, SEED);
+        let currency_id = benchmark_currency::<T>();
+        add_schedules::<T>(&who, currency_id, MAX_SCHEDULES)?;
+    }: _(RawOrigin::Signed(who.clone()), currency_id)
+    verify {
+        assert_eq!(Module::<T>::vesting_schedules(&who, currency_id).len(), MAX_SCHEDULES as usize);
+    }
+
+    vested_transfer {
+        let to: T::AccountId = account("to", 0, SEED);
+        let currency_id = benchmark_currency::<T>();
+        add_schedules::<T>(&to, currency_id, MAX_SCHEDULES - 1)?;
+
+        let origin = T::VestedTransferOrigin::successful_origin();
+        let from = T::VestedTransferOrigin::ensure_origin(origin.clone())
+            .map_err(|_| "the successful origin must be ensured")?;
+        let schedule = benchmark_schedule::<T>();
+        T::Currency::deposit(
+            currency_id,
+            &from,
+            schedule.total_amount().ok_or("schedule amount overflows")?,
+        )?;
+        let call = Call::<T>::vested_transfer(T::Lookup::unlookup(to.clone()), currency_id, schedule);
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert_eq!(Module::<T>::vesting_schedules(&to, currency_id).len(), MAX_SCHEDULES as usize);
+    }
+
+    update_vesting_schedules {
+        let c in 0 .. MAX_SCHEDULES;
+
+        let who: T::AccountId = account("who", 0, SEED);
+        let currency_id = benchmark_currency::<T>();
+        add_schedules::<T>(&who, currency_id, MAX_SCHEDULES)?;
+
+        let origin = T::VestingOrigin::successful_origin();
+        let schedules = vec![benchmark_schedule::<T>(); c as usize];
+        let call = Call::<T>::update_vesting_schedules(
+            T::Lookup::unlookup(who.clone()),
+            currency_id,
+            schedules,
+        );
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert_eq!(Module::<T>::vesting_schedules(&who, currency_id).len(), c as usize);
+    }
+}
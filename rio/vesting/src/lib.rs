@@ -0,0 +1,332 @@
+//! # Rio Vesting Module
+//!
+//! Multi-currency vesting schedules, the vesting balance is locked by
+//! `MultiLockableCurrency::set_lock` of the currency module, e.g. `rio_assets`.
+//!
+//! - `vested_transfer` transfers some balance with a new vesting schedule.
+//! - `claim` unlocks the vested balance.
+//! - `update_vesting_schedules` replaces the vesting schedules of an account.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+mod weight_info;
+
+use codec::{Decode, Encode, HasCompact};
+
+use sp_runtime::{
+    traits::{AtLeast32Bit, CheckedAdd, Saturating, StaticLookup, UniqueSaturatedInto, Zero},
+    DispatchResult, RuntimeDebug,
+};
+use sp_std::prelude::*;
+
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage, ensure,
+    traits::{EnsureOrigin, Get, LockIdentifier},
+};
+use frame_system::ensure_signed;
+
+use orml_traits::{MultiCurrency, MultiLockableCurrency};
+
+pub use weight_info::WeightInfo;
+
+/// The lock id of the vesting balance.
+pub const VESTING_LOCK_ID: LockIdentifier = *b"rio/vest";
+
+/// The vesting schedule.
+///
+/// Benefits are granted gradually, `per_period` amount every `period` of
+/// blocks after `start`.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub struct VestingSchedule<BlockNumber, Balance: HasCompact> {
+    pub start: BlockNumber,
+    pub period: BlockNumber,
+    pub period_count: u32,
+    #[codec(compact)]
+    pub per_period: Balance,
+}
+
+impl<BlockNumber: AtLeast32Bit + Copy, Balance: AtLeast32Bit + Copy>
+    VestingSchedule<BlockNumber, Balance>
+{
+    /// The end of all periods, `None` if it overflows.
+    pub fn end(&self) -> Option<BlockNumber> {
+        self.period
+            .checked_mul(&self.period_count.into())?
+            .checked_add(&self.start)
+    }
+
+    /// The total amount of the schedule, `None` if it overflows.
+    pub fn total_amount(&self) -> Option<Balance> {
+        self.per_period.checked_mul(&self.period_count.into())
+    }
+
+    /// The amount still locked at `time`.
+    ///
+    /// NOTE: the schedule should be checked by `ensure_valid_vesting_schedule` before.
+    pub fn locked_amount(&self, time: BlockNumber) -> Balance {
+        if self.period.is_zero() {
+            return Zero::zero();
+        }
+        let full = time.saturating_sub(self.start) / self.period;
+        let unrealized = self
+            .period_count
+            .saturating_sub(full.unique_saturated_into());
+        self.per_period.saturating_mul(unrealized.into())
+    }
+}
+
+pub type BalanceOf<T> =
+    <<T as Trait>::Currency as MultiCurrency<<T as frame_system::Trait>::AccountId>>::Balance;
+pub type CurrencyIdOf<T> =
+    <<T as Trait>::Currency as MultiCurrency<<T as frame_system::Trait>::AccountId>>::CurrencyId;
+pub type VestingScheduleOf<T> =
+    VestingSchedule<<T as frame_system::Trait>::BlockNumber, BalanceOf<T>>;
+
+pub trait Trait: frame_system::Trait {
+    type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
+
+    /// The currency module which locks the vesting balance
+    type Currency: MultiLockableCurrency<Self::AccountId>;
+
+    /// The minimum amount of a vested transfer
+    type MinVestedTransfer: Get<BalanceOf<Self>>;
+
+    /// The origin which could make a vested transfer, the balance is transferred from the
+    /// returned account
+    type VestedTransferOrigin: EnsureOrigin<Self::Origin, Success = Self::AccountId>;
+
+    /// The origin which could replace the vesting schedules of an account
+    type VestingOrigin: EnsureOrigin<Self::Origin>;
+
+    type WeightInfo: WeightInfo;
+}
+
+decl_storage! {
+    trait Store for Module<T: Trait> as RioVesting {
+        /// The vesting schedules of an account for an asset.
+        pub VestingSchedules get(fn vesting_schedules):
+            double_map hasher(blake2_128_concat) T::AccountId, hasher(twox_64_concat) CurrencyIdOf<T> => Vec<VestingScheduleOf<T>>;
+    }
+    add_extra_genesis {
+        /// who, currency_id, start, period, period_count, per_period
+        config(vesting): Vec<(T::AccountId, CurrencyIdOf<T>, T::BlockNumber, T::BlockNumber, u32, BalanceOf<T>)>;
+        build(|config: &GenesisConfig<T>| {
+            config.vesting.iter().for_each(|(who, currency_id, start, period, period_count, per_period)| {
+                let schedule = VestingSchedule {
+                    start: *start,
+                    period: *period,
+                    period_count: *period_count,
+                    per_period: *per_period,
+                };
+                let total = <Module<T>>::ensure_valid_vesting_schedule(&schedule)
+                    .expect("invalid vesting schedule in genesis");
+                let locked = <Module<T>>::locked_balance(who, *currency_id)
+                    .checked_add(&total)
+                    .expect("vesting balance overflows in genesis");
+                assert!(
+                    T::Currency::free_balance(*currency_id, who) >= locked,
+                    "account does not have enough balance for the vesting in genesis"
+                );
+
+                T::Currency::set_lock(VESTING_LOCK_ID, *currency_id, who, locked);
+                VestingSchedules::<T>::mutate(who, currency_id, |schedules| schedules.push(schedule));
+            });
+        });
+    }
+}
+
+decl_event!(
+    pub enum Event<T> where
+        <T as frame_system::Trait>::AccountId,
+        CurrencyId = CurrencyIdOf<T>,
+        Balance = BalanceOf<T>,
+        VestingSchedule = VestingScheduleOf<T>,
+    {
+        /// Added a new vesting schedule. [from, to, currency_id, vesting_schedule]
+        VestingScheduleAdded(AccountId, AccountId, CurrencyId, VestingSchedule),
+        /// Claimed the vested balance. [who, currency_id, locked_amount]
+        Claimed(AccountId, CurrencyId, Balance),
+        /// Updated the vesting schedules of an account. [who, currency_id]
+        VestingSchedulesUpdated(AccountId, CurrencyId),
+    }
+);
+
+decl_error! {
+    /// Error for the rio-vesting module.
+    pub enum Error for Module<T: Trait> {
+        /// The period of a vesting schedule is zero
+        ZeroVestingPeriod,
+        /// The period count of a vesting schedule is zero
+        ZeroVestingPeriodCount,
+        /// The end or the total amount of a vesting schedule overflows
+        NumOverflow,
+        /// The free balance is lower than the vesting balance
+        InsufficientBalanceToLock,
+        /// The amount of a vested transfer is lower than `MinVestedTransfer`
+        AmountLow,
+        /// An account has more than `MAX_VESTING_SCHEDULES` schedules for an asset
+        TooManyVestingSchedules,
+    }
+}
+
+decl_module! {
+    pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        type Error = Error<T>;
+
+        const MinVestedTransfer: BalanceOf<T> = T::MinVestedTransfer::get();
+
+        fn deposit_event() = default;
+
+        /// Unlock the vested balance of the caller for an asset.
+        #[weight = T::WeightInfo::claim()]
+        pub fn claim(origin, currency_id: CurrencyIdOf<T>) {
+            let who = ensure_signed(origin)?;
+            let locked_amount = Self::do_claim(&who, currency_id);
+
+            Self::deposit_event(RawEvent::Claimed(who, currency_id, locked_amount));
+        }
+
+        /// Transfer some balance to `dest` with a new vesting schedule, the amount is the
+        /// total amount of the schedule.
+        ///
+        /// The dispatch origin for this call must be `VestedTransferOrigin`.
+        #[weight = T::WeightInfo::vested_transfer()]
+        pub fn vested_transfer(
+            origin,
+            dest: <T::Lookup as StaticLookup>::Source,
+            currency_id: CurrencyIdOf<T>,
+            schedule: VestingScheduleOf<T>,
+        ) {
+            let from = T::VestedTransferOrigin::ensure_origin(origin)?;
+            let to = T::Lookup::lookup(dest)?;
+            Self::do_vested_transfer(&from, &to, currency_id, schedule.clone())?;
+
+            Self::deposit_event(RawEvent::VestingScheduleAdded(from, to, currency_id, schedule));
+        }
+
+        /// Replace the vesting schedules of an account for an asset.
+        ///
+        /// The dispatch origin for this call must be `VestingOrigin`.
+        #[weight = T::WeightInfo::update_vesting_schedules(vesting_schedules.len() as u32)]
+        pub fn update_vesting_schedules(
+            origin,
+            who: <T::Lookup as StaticLookup>::Source,
+            currency_id: CurrencyIdOf<T>,
+            vesting_schedules: Vec<VestingScheduleOf<T>>,
+        ) {
+            T::VestingOrigin::ensure_origin(origin)?;
+            let account = T::Lookup::lookup(who)?;
+            Self::do_update_vesting_schedules(&account, currency_id, vesting_schedules)?;
+
+            Self::deposit_event(RawEvent::VestingSchedulesUpdated(account, currency_id));
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    fn do_claim(who: &T::AccountId, currency_id: CurrencyIdOf<T>) -> BalanceOf<T> {
+        let locked = Self::locked_balance(who, currency_id);
+        if locked.is_zero() {
+            T::Currency::remove_lock(VESTING_LOCK_ID, currency_id, who);
+        } else {
+            T::Currency::set_lock(VESTING_LOCK_ID, currency_id, who, locked);
+        }
+        locked
+    }
+
+    /// The balance still locked by the schedules of `who`, the finished schedules are removed.
+    fn locked_balance(who: &T::AccountId, currency_id: CurrencyIdOf<T>) -> BalanceOf<T> {
+        let now = frame_system::Module::<T>::block_number();
+        let mut schedules = Self::vesting_schedules(who, currency_id);
+        let len = schedules.len();
+        schedules.retain(|s| !s.locked_amount(now).is_zero());
+
+        if schedules.len() != len {
+            if schedules.is_empty() {
+                VestingSchedules::<T>::remove(who, currency_id);
+            } else {
+                VestingSchedules::<T>::insert(who, currency_id, &schedules);
+            }
+        }
+        schedules.iter().fold(Zero::zero(), |acc: BalanceOf<T>, s| {
+            acc.saturating_add(s.locked_amount(now))
+        })
+    }
+
+    fn do_vested_transfer(
+        from: &T::AccountId,
+        to: &T::AccountId,
+        currency_id: CurrencyIdOf<T>,
+        schedule: VestingScheduleOf<T>,
+    ) -> DispatchResult {
+        let schedule_amount = Self::ensure_valid_vesting_schedule(&schedule)?;
+        ensure!(
+            schedule_amount >= T::MinVestedTransfer::get(),
+            Error::<T>::AmountLow
+        );
+
+        let locked = Self::locked_balance(to, currency_id)
+            .checked_add(&schedule_amount)
+            .ok_or(Error::<T>::NumOverflow)?;
+        let mut schedules = Self::vesting_schedules(to, currency_id);
+        ensure!(
+            schedules.len() < rio_protocol::MAX_VESTING_SCHEDULES,
+            Error::<T>::TooManyVestingSchedules
+        );
+
+        T::Currency::transfer(currency_id, from, to, schedule_amount)?;
+        T::Currency::set_lock(VESTING_LOCK_ID, currency_id, to, locked);
+        schedules.push(schedule);
+        VestingSchedules::<T>::insert(to, currency_id, schedules);
+        Ok(())
+    }
+
+    fn do_update_vesting_schedules(
+        who: &T::AccountId,
+        currency_id: CurrencyIdOf<T>,
+        schedules: Vec<VestingScheduleOf<T>>,
+    ) -> DispatchResult {
+        ensure!(
+            schedules.len() <= rio_protocol::MAX_VESTING_SCHEDULES,
+            Error::<T>::TooManyVestingSchedules
+        );
+        let mut locked: BalanceOf<T> = Zero::zero();
+        for schedule in schedules.iter() {
+            locked = locked
+                .checked_add(&Self::ensure_valid_vesting_schedule(schedule)?)
+                .ok_or(Error::<T>::NumOverflow)?;
+        }
+        ensure!(
+            T::Currency::free_balance(currency_id, who) >= locked,
+            Error::<T>::InsufficientBalanceToLock
+        );
+
+        if schedules.is_empty() {
+            T::Currency::remove_lock(VESTING_LOCK_ID, currency_id, who);
+            VestingSchedules::<T>::remove(who, currency_id);
+        } else {
+            T::Currency::set_lock(VESTING_LOCK_ID, currency_id, who, locked);
+            VestingSchedules::<T>::insert(who, currency_id, schedules);
+        }
+        Ok(())
+    }
+
+    /// Returns the total amount of a valid schedule.
+    fn ensure_valid_vesting_schedule(
+        schedule: &VestingScheduleOf<T>,
+    ) -> Result<BalanceOf<T>, Error<T>> {
+        ensure!(!schedule.period.is_zero(), Error::<T>::ZeroVestingPeriod);
+        ensure!(
+            !schedule.period_count.is_zero(),
+            Error::<T>::ZeroVestingPeriodCount
+        );
+        ensure!(schedule.end().is_some(), Error::<T>::NumOverflow);
+
+        schedule.total_amount().ok_or(Error::<T>::NumOverflow)
+    }
+}
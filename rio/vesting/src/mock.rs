@@ -0,0 +1,211 @@
+use super::*;
+use frame_support::{
+    impl_outer_event, impl_outer_origin, parameter_types, weights::RuntimeDbWeight,
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use rio_assets::{AssetInfo, Chain, Restrictions};
+use rio_primitives::{Amount, Balance, CurrencyId};
+use sp_core::H256;
+
+#[allow(unused_imports)]
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+    Perbill,
+};
+
+pub mod constants {
+    use super::{Balance, CurrencyId, TestRuntime};
+
+    pub const ALICE: <TestRuntime as frame_system::Trait>::AccountId = 2;
+    pub const BOB: <TestRuntime as frame_system::Trait>::AccountId = 3;
+    pub const CHRIS: <TestRuntime as frame_system::Trait>::AccountId = 4;
+
+    pub const RBTC: CurrencyId = 100;
+
+    pub const NATIVE_ENDOWMENT: Balance = 1000;
+    pub const MIN_VESTED_TRANSFER: Balance = 5;
+}
+
+use self::constants::*;
+
+pub type AccountId = u64;
+pub type BlockNumber = u64;
+
+impl_outer_origin! {
+    pub enum Origin for TestRuntime where system = frame_system {}
+}
+
+mod rio_vesting {
+    pub use crate::Event;
+}
+
+impl_outer_event! {
+    pub enum TestEvent for TestRuntime {
+        frame_system<T>,
+        pallet_balances<T>,
+        rio_assets<T>,
+        rio_vesting<T>,
+    }
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct TestRuntime;
+const AVERAGE_ON_INITIALIZE_WEIGHT: Perbill = Perbill::from_percent(10);
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const MaximumBlockWeight: u32 = 1024;
+    pub const MaximumBlockLength: u32 = 2 * 1024;
+    pub const AvailableBlockRatio: Perbill = Perbill::one();
+    pub const BlockExecutionWeight: u32 = 10;
+    pub const ExtrinsicBaseWeight: u32 = 5;
+    pub MaximumExtrinsicWeight: u32 =
+        AvailableBlockRatio::get().saturating_sub(AVERAGE_ON_INITIALIZE_WEIGHT)
+        * MaximumBlockWeight::get();
+    pub const DbWeight: RuntimeDbWeight = RuntimeDbWeight {
+        read: 10,
+        write: 100,
+    };
+}
+
+impl frame_system::Trait for TestRuntime {
+    type BaseCallFilter = ();
+    type Origin = Origin;
+    type Call = ();
+    type Index = u64;
+    type BlockNumber = BlockNumber;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = TestEvent;
+    type BlockHashCount = BlockHashCount;
+    type MaximumBlockWeight = MaximumBlockWeight;
+    type DbWeight = DbWeight;
+    type BlockExecutionWeight = BlockExecutionWeight;
+    type ExtrinsicBaseWeight = ExtrinsicBaseWeight;
+    type MaximumExtrinsicWeight = MaximumExtrinsicWeight;
+
+    type MaximumBlockLength = MaximumBlockLength;
+    type AvailableBlockRatio = AvailableBlockRatio;
+    type Version = ();
+    type PalletInfo = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type SystemWeightInfo = ();
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Trait for TestRuntime {
+    type Balance = Balance;
+    type Event = TestEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type MaxLocks = ();
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const AssetDeposit: Balance = 100;
+    pub const UserAssetIdStart: CurrencyId = rio_protocol::USER_ASSET_START;
+    pub const MaxDestroyAccounts: u32 = 100;
+    pub const MaxSnapshots: u32 = 10;
+}
+
+impl rio_assets::Trait for TestRuntime {
+    type Event = TestEvent;
+    type Balance = Balance;
+    type Amount = Amount;
+    type CurrencyId = CurrencyId;
+    type OnReceived = ();
+    type OnDust = ();
+    type NativeCurrency = Balances;
+    type AssetDeposit = AssetDeposit;
+    type UserAssetIdStart = UserAssetIdStart;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type MaxDestroyAccounts = MaxDestroyAccounts;
+    type MaxSnapshots = MaxSnapshots;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const MinVestedTransfer: Balance = MIN_VESTED_TRANSFER;
+}
+
+impl Trait for TestRuntime {
+    type Event = TestEvent;
+    type Currency = RioAssets;
+    type MinVestedTransfer = MinVestedTransfer;
+    type VestedTransferOrigin = EnsureSigned<AccountId>;
+    type VestingOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = ();
+}
+
+pub type System = frame_system::Module<TestRuntime>;
+pub type Balances = pallet_balances::Module<TestRuntime>;
+pub type RioAssets = rio_assets::Module<TestRuntime>;
+pub type RioVesting = Module<TestRuntime>;
+pub type RioVestingErr = Error<TestRuntime>;
+
+pub struct ExtBuilder {}
+
+impl Default for ExtBuilder {
+    fn default() -> Self {
+        Self {}
+    }
+}
+
+impl ExtBuilder {
+    pub fn build(self) -> sp_io::TestExternalities {
+        new_test_ext()
+    }
+}
+
+// This function basically just builds a genesis storage key/value store according to
+// our desired mockup.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::default()
+        .build_storage::<TestRuntime>()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<TestRuntime> {
+        balances: vec![(ALICE, NATIVE_ENDOWMENT), (BOB, NATIVE_ENDOWMENT)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    rio_assets::GenesisConfig::<TestRuntime> {
+        init: vec![(
+            RBTC,
+            AssetInfo {
+                symbol: b"RBTC".to_vec(),
+                name: b"RBTC token".to_vec(),
+                decimals: 8,
+                desc: b"Bitcoin in RioChain".to_vec(),
+                chain: Chain::Bitcoin.id(),
+                min_balance: 0,
+            },
+            Restrictions::none(),
+            vec![(ALICE, 1000), (CHRIS, 100)],
+        )],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    // CHRIS: 10 every 10 blocks from block 0, 100 in total
+    GenesisConfig::<TestRuntime> {
+        vesting: vec![(CHRIS, RBTC, 0, 10, 10, 10)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext: sp_io::TestExternalities = t.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
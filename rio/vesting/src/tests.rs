@@ -0,0 +1,244 @@
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use rio_primitives::Balance;
+use sp_runtime::DispatchError;
+
+use super::mock::{
+    constants::*, ExtBuilder, Origin, RioAssets, RioVesting, RioVestingErr, System, TestEvent,
+    TestRuntime,
+};
+
+fn vesting_locked(who: &u64) -> Balance {
+    RioAssets::locks(who, RBTC)
+        .into_iter()
+        .find(|l| l.id == VESTING_LOCK_ID)
+        .map(|l| l.amount)
+        .unwrap_or_default()
+}
+
+fn last_vesting_event() -> Option<RawEvent<u64, u32, Balance, VestingScheduleOf<TestRuntime>>> {
+    System::events()
+        .into_iter()
+        .filter_map(|r| match r.event {
+            TestEvent::rio_vesting(e) => Some(e),
+            _ => None,
+        })
+        .last()
+}
+
+fn schedule(
+    start: u64,
+    period: u64,
+    period_count: u32,
+    per_period: Balance,
+) -> VestingScheduleOf<TestRuntime> {
+    VestingSchedule {
+        start,
+        period,
+        period_count,
+        per_period,
+    }
+}
+
+#[test]
+fn genesis_vesting_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(
+            RioVesting::vesting_schedules(CHRIS, RBTC),
+            vec![schedule(0, 10, 10, 10)]
+        );
+        assert_eq!(vesting_locked(&CHRIS), 100);
+        assert_noop!(
+            RioAssets::transfer(Origin::signed(CHRIS), BOB, RBTC, 1),
+            rio_assets::Error::<TestRuntime>::LiquidityRestrictions
+        );
+    });
+}
+
+#[test]
+fn vesting_schedule_locked_amount_works() {
+    let s = schedule(10, 5, 4, 25);
+    assert_eq!(s.end(), Some(30));
+    assert_eq!(s.total_amount(), Some(100));
+    assert_eq!(s.locked_amount(0), 100);
+    assert_eq!(s.locked_amount(14), 100);
+    assert_eq!(s.locked_amount(15), 75);
+    assert_eq!(s.locked_amount(29), 25);
+    assert_eq!(s.locked_amount(30), 0);
+    assert_eq!(s.locked_amount(100), 0);
+
+    assert_eq!(schedule(u64::MAX, 2, 2, 1).end(), None);
+    assert_eq!(schedule(0, 1, 2, Balance::MAX).total_amount(), None);
+}
+
+#[test]
+fn claim_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(25);
+        assert_ok!(RioVesting::claim(Origin::signed(CHRIS), RBTC));
+        assert_eq!(vesting_locked(&CHRIS), 80);
+        assert_eq!(
+            last_vesting_event(),
+            Some(RawEvent::Claimed(CHRIS, RBTC, 80))
+        );
+
+        assert_ok!(RioAssets::transfer(Origin::signed(CHRIS), BOB, RBTC, 20));
+        assert_noop!(
+            RioAssets::transfer(Origin::signed(CHRIS), BOB, RBTC, 1),
+            rio_assets::Error::<TestRuntime>::LiquidityRestrictions
+        );
+
+        System::set_block_number(100);
+        assert_ok!(RioVesting::claim(Origin::signed(CHRIS), RBTC));
+        assert_eq!(vesting_locked(&CHRIS), 0);
+        assert!(RioAssets::locks(CHRIS, RBTC).is_empty());
+        assert!(RioVesting::vesting_schedules(CHRIS, RBTC).is_empty());
+        assert_ok!(RioAssets::transfer(Origin::signed(CHRIS), BOB, RBTC, 80));
+    });
+}
+
+#[test]
+fn vested_transfer_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let s = schedule(1, 5, 2, 50);
+        assert_ok!(RioVesting::vested_transfer(
+            Origin::signed(ALICE),
+            BOB,
+            RBTC,
+            s.clone()
+        ));
+        assert_eq!(RioAssets::free_balance(RBTC, &ALICE), 900);
+        assert_eq!(RioAssets::free_balance(RBTC, &BOB), 100);
+        assert_eq!(vesting_locked(&BOB), 100);
+        assert_eq!(RioVesting::vesting_schedules(BOB, RBTC), vec![s.clone()]);
+        assert_eq!(
+            last_vesting_event(),
+            Some(RawEvent::VestingScheduleAdded(ALICE, BOB, RBTC, s))
+        );
+
+        // the new schedule is added on top of the existing one
+        let s2 = schedule(1, 10, 1, 10);
+        assert_ok!(RioVesting::vested_transfer(
+            Origin::signed(ALICE),
+            CHRIS,
+            RBTC,
+            s2
+        ));
+        assert_eq!(RioVesting::vesting_schedules(CHRIS, RBTC).len(), 2);
+        assert_eq!(vesting_locked(&CHRIS), 110);
+
+        System::set_block_number(6);
+        assert_ok!(RioVesting::claim(Origin::signed(BOB), RBTC));
+        assert_eq!(vesting_locked(&BOB), 50);
+    });
+}
+
+#[test]
+fn vested_transfer_fails_if_invalid() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            RioVesting::vested_transfer(Origin::signed(ALICE), BOB, RBTC, schedule(1, 0, 2, 10)),
+            RioVestingErr::ZeroVestingPeriod
+        );
+        assert_noop!(
+            RioVesting::vested_transfer(Origin::signed(ALICE), BOB, RBTC, schedule(1, 1, 0, 10)),
+            RioVestingErr::ZeroVestingPeriodCount
+        );
+        assert_noop!(
+            RioVesting::vested_transfer(
+                Origin::signed(ALICE),
+                BOB,
+                RBTC,
+                schedule(u64::MAX, 1, 2, 10)
+            ),
+            RioVestingErr::NumOverflow
+        );
+        assert_noop!(
+            RioVesting::vested_transfer(
+                Origin::signed(ALICE),
+                BOB,
+                RBTC,
+                schedule(1, 1, 2, Balance::MAX)
+            ),
+            RioVestingErr::NumOverflow
+        );
+        assert_noop!(
+            RioVesting::vested_transfer(Origin::signed(ALICE), BOB, RBTC, schedule(1, 1, 2, 2)),
+            RioVestingErr::AmountLow
+        );
+        assert_noop!(
+            RioVesting::vested_transfer(Origin::signed(ALICE), BOB, RBTC, schedule(1, 1, 2, 600)),
+            rio_assets::Error::<TestRuntime>::BalanceTooLow
+        );
+    });
+}
+
+#[test]
+fn vested_transfer_fails_if_too_many_schedules() {
+    ExtBuilder::default().build().execute_with(|| {
+        for _ in 0..rio_protocol::MAX_VESTING_SCHEDULES {
+            assert_ok!(RioVesting::vested_transfer(
+                Origin::signed(ALICE),
+                BOB,
+                RBTC,
+                schedule(1, 10, 1, MIN_VESTED_TRANSFER)
+            ));
+        }
+        assert_noop!(
+            RioVesting::vested_transfer(
+                Origin::signed(ALICE),
+                BOB,
+                RBTC,
+                schedule(1, 10, 1, MIN_VESTED_TRANSFER)
+            ),
+            RioVestingErr::TooManyVestingSchedules
+        );
+    });
+}
+
+#[test]
+fn update_vesting_schedules_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let s = schedule(0, 10, 10, 5);
+        assert_noop!(
+            RioVesting::update_vesting_schedules(
+                Origin::signed(ALICE),
+                CHRIS,
+                RBTC,
+                vec![s.clone()]
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            RioVesting::update_vesting_schedules(
+                Origin::root(),
+                CHRIS,
+                RBTC,
+                vec![schedule(0, 10, 10, 20)]
+            ),
+            RioVestingErr::InsufficientBalanceToLock
+        );
+
+        assert_ok!(RioVesting::update_vesting_schedules(
+            Origin::root(),
+            CHRIS,
+            RBTC,
+            vec![s.clone()]
+        ));
+        assert_eq!(RioVesting::vesting_schedules(CHRIS, RBTC), vec![s]);
+        assert_eq!(vesting_locked(&CHRIS), 50);
+        assert_eq!(
+            last_vesting_event(),
+            Some(RawEvent::VestingSchedulesUpdated(CHRIS, RBTC))
+        );
+
+        assert_ok!(RioVesting::update_vesting_schedules(
+            Origin::root(),
+            CHRIS,
+            RBTC,
+            vec![]
+        ));
+        assert!(RioVesting::vesting_schedules(CHRIS, RBTC).is_empty());
+        assert!(RioAssets::locks(CHRIS, RBTC).is_empty());
+    });
+}
@@ -0,0 +1,32 @@
+use frame_support::weights::{
+    constants::{RocksDbWeight as DbWeight, WEIGHT_PER_MICROS},
+    Weight,
+};
+pub trait WeightInfo {
+    fn claim() -> Weight;
+    fn vested_transfer() -> Weight;
+    fn update_vesting_schedules(c: u32) -> Weight;
+}
+
+impl WeightInfo for () {
+    fn claim() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(60)
+            .saturating_add(DbWeight::get().reads_writes(3, 3))
+    }
+    fn vested_transfer() -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(120)
+            .saturating_add(DbWeight::get().reads_writes(7, 6))
+    }
+    fn update_vesting_schedules(c: u32) -> Weight {
+        WEIGHT_PER_MICROS
+            .saturating_mul(50)
+            .saturating_add(
+                WEIGHT_PER_MICROS
+                    .saturating_mul(2)
+                    .saturating_mul(c as Weight),
+            )
+            .saturating_add(DbWeight::get().reads_writes(3, 3))
+    }
+}
@@ -846,7 +846,8 @@ impl EnsureOrigin<Origin> for EnsureRootOrTreasury {
 
     #[cfg(feature = "runtime-benchmarks")]
     fn successful_origin() -> Origin {
-        Origin::from(RawOrigin::Signed(Default::default()))
+        // the default account is not the treasury
+        Origin::from(RawOrigin::Root)
     }
 }
 
@@ -878,24 +879,51 @@ impl orml_oracle::Trait for Runtime {
 pub type TimeStampedPrice = orml_oracle::TimestampedValueOf<Runtime>;
 
 // rio
+parameter_types! {
+    pub const AssetDeposit: Balance = 100 * DOLLARS;
+    pub const UserAssetIdStart: CurrencyId = rio_protocol::USER_ASSET_START;
+    pub const MaxDestroyAccounts: u32 = 1000;
+    pub const MaxSnapshots: u32 = 100;
+    pub TreasuryAccount: AccountId = TreasuryModuleId::get().into_account();
+    /// The modules whose accounts are listed by `AssetsApi::module_accounts`.
+    pub ModuleAccountIds: Vec<ModuleId> = vec![TreasuryModuleId::get(), SocietyModuleId::get()];
+    pub const LockedRFuelId: CurrencyId = rio_protocol::LOCKED_RFUEL;
+}
+
+/// The handlers of the balance received in `RioAssets`, matched by the currency or the account.
+pub type AssetsOnReceived = rio_assets::ReceivedHandlers<(
+    // locked rfuel is only used as the reserved balance for the fee
+    rio_assets::ForCurrency<LockedRFuelId, rio_assets::ReserveReceived<Runtime>>,
+)>;
+
 impl rio_assets::Trait for Runtime {
     type Event = Event;
     type Balance = Balance;
     type Amount = Amount;
     type CurrencyId = CurrencyId;
-    type OnReceived = (); // module_accounts::Module<Runtime>;
-    type WeightInfo = ();
+    type OnReceived = AssetsOnReceived;
+    type OnDust = rio_assets::TransferDust<Runtime, TreasuryAccount>;
+    type NativeCurrency = Balances;
+    type AssetDeposit = AssetDeposit;
+    type UserAssetIdStart = UserAssetIdStart;
+    type ManagerOrigin = EnsureRootOrManager;
+    type MaxDestroyAccounts = MaxDestroyAccounts;
+    type MaxSnapshots = MaxSnapshots;
+    type WeightInfo = weights::rio_assets::WeightInfo;
 }
 
 impl rio_assets_ext::Trait for Runtime {
     type Event = Event;
+    type ScheduledCall = Call;
+    type Scheduler = Scheduler;
+    type PalletsOrigin = OriginCaller;
 }
 
 impl rio_payment_fee::Trait for Runtime {
     type Event = Event;
     type Currency = Balances;
     type MultiCurrency = RioAssets;
-    type WeightInfo = ();
+    type WeightInfo = weights::rio_payment_fee::WeightInfo;
 }
 
 parameter_types! {
@@ -912,10 +940,17 @@ impl rio_payment::Trait for Runtime {
     type BuyFeeAsset = RioPaymentFee;
 }
 
+parameter_types! {
+    pub const BlocksPerDay: BlockNumber = DAYS;
+}
+
 impl rio_gateway::Trait for Runtime {
     type Event = Event;
     type Currency = Currencies;
-    type WeightInfo = ();
+    type BlocksPerDay = BlocksPerDay;
+    type TreasuryAccount = TreasuryAccount;
+    type AddressChecker = RioAssets;
+    type WeightInfo = weights::rio_gateway::WeightInfo;
 }
 
 type EnsureRootOrHalfGeneralCouncil = EnsureOneOf<
@@ -936,7 +971,21 @@ impl rio_root::Trait for Runtime {
     type Event = Event;
     type Currency = RioAssets;
     type RootOrigin = EnsureRootOrManager;
-    type WeightInfo = ();
+    type WeightInfo = weights::rio_root::WeightInfo;
+}
+
+parameter_types! {
+    // vested transfers are made by root or treasury only, and assets have different decimals
+    pub const AssetsMinVestedTransfer: Balance = 0;
+}
+
+impl rio_vesting::Trait for Runtime {
+    type Event = Event;
+    type Currency = RioAssets;
+    type MinVestedTransfer = AssetsMinVestedTransfer;
+    type VestedTransferOrigin = EnsureRootOrTreasury;
+    type VestingOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = weights::rio_vesting::WeightInfo;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -995,6 +1044,7 @@ construct_runtime!(
         RioPrices: rio_prices::{Module, Storage, Call, Event},
 
         RioRoot: rio_root::{Module, Storage, Call, Config<T>, Event<T>},
+        RioVesting: rio_vesting::{Module, Storage, Call, Config<T>, Event<T>},
     }
 );
 
@@ -1177,6 +1227,34 @@ impl_runtime_apis! {
         }
     }
 
+    impl rio_assets_rpc_runtime_api::AssetsApi<
+        Block,
+        CurrencyId,
+        AccountId,
+        Balance,
+        BlockNumber,
+    > for Runtime {
+        fn balance_at(currency_id: CurrencyId, who: AccountId, snapshot_id: rio_assets::SnapshotId) -> Option<Balance> {
+            RioAssets::balance_at(currency_id, &who, snapshot_id)
+        }
+
+        fn holders(currency_id: CurrencyId, offset: u32, limit: u32) -> Vec<(AccountId, Balance)> {
+            RioAssets::holders(currency_id, offset, limit)
+        }
+
+        fn asset_info_at(currency_id: CurrencyId, block: BlockNumber) -> Option<rio_assets::AssetInfo<Balance>> {
+            RioAssets::asset_info_at(currency_id, block)
+        }
+
+        fn check_invariants() -> Vec<rio_assets::Discrepancy<AccountId, CurrencyId, Balance>> {
+            RioAssets::check_invariants()
+        }
+
+        fn module_accounts() -> Vec<(ModuleId, AccountId, Vec<(CurrencyId, rio_assets::AccountData<Balance>)>)> {
+            RioAssets::module_account_balances(&ModuleAccountIds::get())
+        }
+    }
+
     impl rio_gateway_rpc_runtime_api::GatewayApi<
         Block,
         CurrencyId,
@@ -1235,6 +1313,13 @@ impl_runtime_apis! {
             );
             // Substrate
             add_benchmark!(params, batches, pallet_balances, Balances);
+            // Rio
+            add_benchmark!(params, batches, rio_assets, RioAssets);
+            add_benchmark!(params, batches, rio_assets_ext, RioAssetsExt);
+            add_benchmark!(params, batches, rio_gateway, RioGateway);
+            add_benchmark!(params, batches, rio_payment_fee, RioPaymentFee);
+            add_benchmark!(params, batches, rio_root, RioRoot);
+            add_benchmark!(params, batches, rio_vesting, RioVesting);
 
             if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
             Ok(batches)
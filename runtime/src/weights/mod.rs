@@ -30,3 +30,8 @@ pub mod pallet_staking;
 pub mod pallet_timestamp;
 pub mod pallet_treasury;
 pub mod pallet_utility;
+pub mod rio_assets;
+pub mod rio_gateway;
+pub mod rio_payment_fee;
+pub mod rio_root;
+pub mod rio_vesting;
@@ -0,0 +1,185 @@
+//! Weights for rio_assets.
+//!
+//! The values are the defaults of the module until the benchmarks are run on the reference
+//! hardware, regenerate this file with:
+//!
+//! ./target/release/riochain benchmark --chain=dev --steps=50 --repeat=20 --pallet=rio_assets
+//!     --extrinsic='*' --execution=wasm --wasm-execution=compiled --heap-pages=4096
+//!     --output=./runtime/src/weights/rio_assets.rs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+impl rio_assets::WeightInfo for WeightInfo {
+    fn transfer() -> Weight {
+        (100000000000 as Weight)
+    }
+    fn transfer_all() -> Weight {
+        (88000000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn create() -> Weight {
+        (88000000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn update_asset_info() -> Weight {
+        (88000000 as Weight)
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn update_restriction() -> Weight {
+        (88000000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn offline_asset() -> Weight {
+        (88000000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn online_asset() -> Weight {
+        (88000000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn issue() -> Weight {
+        (88000000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+    }
+    fn mint() -> Weight {
+        (88000000 as Weight)
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn burn() -> Weight {
+        (88000000 as Weight)
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn set_issuer() -> Weight {
+        (60000000 as Weight)
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn transfer_ownership() -> Weight {
+        (88000000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn destroy(c: u32) -> Weight {
+        (88000000 as Weight)
+            .saturating_add((10000000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(DbWeight::get().reads(9 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+            .saturating_add(DbWeight::get().writes(8 as Weight))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+    }
+    fn set_max_supply() -> Weight {
+        (60000000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_account_restrictions() -> Weight {
+        (60000000 as Weight)
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn freeze_account() -> Weight {
+        (60000000 as Weight)
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn thaw_account() -> Weight {
+        (60000000 as Weight).saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn approve() -> Weight {
+        (60000000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn transfer_from() -> Weight {
+        (100000000 as Weight)
+            .saturating_add(DbWeight::get().reads(7 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn batch_transfer(c: u32) -> Weight {
+        (20000000 as Weight)
+            .saturating_add((30000000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(DbWeight::get().reads((4 as Weight).saturating_mul(c as Weight)))
+            .saturating_add(DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+    }
+    fn transfer_with_memo() -> Weight {
+        (70000000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn start_destroy_asset() -> Weight {
+        (30000000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn destroy_asset(c: u32) -> Weight {
+        (100000000 as Weight)
+            .saturating_add((40000000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().reads((3 as Weight).saturating_mul(c as Weight)))
+            .saturating_add(DbWeight::get().writes(8 as Weight))
+            .saturating_add(DbWeight::get().writes((5 as Weight).saturating_mul(c as Weight)))
+    }
+    fn snapshot() -> Weight {
+        (35000000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn unlock_expired() -> Weight {
+        (45000000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn register_chain() -> Weight {
+        (25000000 as Weight)
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn update_chain() -> Weight {
+        (25000000 as Weight)
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn increase_allowance() -> Weight {
+        (60000000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn decrease_allowance() -> Weight {
+        (60000000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn pause_asset() -> Weight {
+        (35000000 as Weight)
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn unpause_asset() -> Weight {
+        (30000000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn schedule_pause() -> Weight {
+        (50000000 as Weight)
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+    }
+    fn cancel_scheduled_pause() -> Weight {
+        (60000000 as Weight)
+            .saturating_add(DbWeight::get().reads(7 as Weight))
+            .saturating_add(DbWeight::get().writes(7 as Weight))
+    }
+}
@@ -0,0 +1,132 @@
+//! Weights for rio_gateway.
+//!
+//! The values are the defaults of the module until the benchmarks are run on the reference
+//! hardware, regenerate this file with:
+//!
+//! ./target/release/riochain benchmark --chain=dev --steps=50 --repeat=20 --pallet=rio_gateway
+//!     --extrinsic='*' --execution=wasm --wasm-execution=compiled --heap-pages=4096
+//!     --output=./runtime/src/weights/rio_gateway.rs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+impl rio_gateway::WeightInfo for WeightInfo {
+    fn set_auth(a: u32, t: u32, c: u32) -> Weight {
+        (80000000 as Weight)
+            .saturating_add((5000000 as Weight).saturating_mul(a as Weight))
+            .saturating_add((10000000 as Weight).saturating_mul(t as Weight))
+            .saturating_add((10000000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().reads((2 as Weight).saturating_mul(a as Weight)))
+            .saturating_add(DbWeight::get().reads((2 as Weight).saturating_mul(t as Weight)))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+    }
+    fn register_asset() -> Weight {
+        (60000000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn remove_asset() -> Weight {
+        (80000000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn set_bip32_info() -> Weight {
+        (80000000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn set_withdrawal_fee() -> Weight {
+        (60000000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn apply_deposit_address() -> Weight {
+        (80000000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn set_max_deposit_index() -> Weight {
+        (80000000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn deposit() -> Weight {
+        (100000000 as Weight)
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn withdraw() -> Weight {
+        (120000000 as Weight)
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(7 as Weight))
+    }
+    fn revoke_withdraw() -> Weight {
+        (81000000 as Weight)
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+    }
+    fn reject_withdraw() -> Weight {
+        (81000000 as Weight)
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+    }
+    fn approve_withdraw() -> Weight {
+        (100000000 as Weight)
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn withdraw_finish() -> Weight {
+        (60000000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn rebroadcast() -> Weight {
+        (80000000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn modify_withdraw_state() -> Weight {
+        (81000000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn set_deposit_threshold() -> Weight {
+        (60000000 as Weight)
+            .saturating_add(DbWeight::get().reads(10 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_withdraw_quorum() -> Weight {
+        (60000000 as Weight)
+            .saturating_add(DbWeight::get().reads(10 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_withdraw_limits() -> Weight {
+        (60000000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn review_withdraw() -> Weight {
+        (81000000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn set_withdraw_fee_schedule() -> Weight {
+        (60000000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_withdraw_fee_account() -> Weight {
+        (30000000 as Weight).saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn remove_deposit_confirmations() -> Weight {
+        (30000000 as Weight)
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+}
@@ -0,0 +1,22 @@
+//! Weights for rio_payment_fee.
+//!
+//! The values are the defaults of the module until the benchmarks are run on the reference
+//! hardware, regenerate this file with:
+//!
+//! ./target/release/riochain benchmark --chain=dev --steps=50 --repeat=20 --pallet=rio_payment_fee
+//!     --extrinsic='*' --execution=wasm --wasm-execution=compiled --heap-pages=4096
+//!     --output=./runtime/src/weights/rio_payment_fee.rs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+impl rio_payment_fee::WeightInfo for WeightInfo {
+    fn set_account() -> Weight {
+        (84000000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+}
@@ -0,0 +1,32 @@
+//! Weights for rio_root.
+//!
+//! The values are the defaults of the module until the benchmarks are run on the reference
+//! hardware, regenerate this file with:
+//!
+//! ./target/release/riochain benchmark --chain=dev --steps=50 --repeat=20 --pallet=rio_root
+//!     --extrinsic='*' --execution=wasm --wasm-execution=compiled --heap-pages=4096
+//!     --output=./runtime/src/weights/rio_root.rs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+impl rio_root::WeightInfo for WeightInfo {
+    fn modify_manager() -> Weight {
+        (84000000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn issue_locked_fee() -> Weight {
+        (84000000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn toggle_blacklist() -> Weight {
+        (84000000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+}
@@ -0,0 +1,33 @@
+//! Weights for rio_vesting.
+//!
+//! The values are the defaults of the module until the benchmarks are run on the reference
+//! hardware, regenerate this file with:
+//!
+//! ./target/release/riochain benchmark --chain=dev --steps=50 --repeat=20 --pallet=rio_vesting
+//!     --extrinsic='*' --execution=wasm --wasm-execution=compiled --heap-pages=4096
+//!     --output=./runtime/src/weights/rio_vesting.rs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+impl rio_vesting::WeightInfo for WeightInfo {
+    fn claim() -> Weight {
+        (60000000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn vested_transfer() -> Weight {
+        (120000000 as Weight)
+            .saturating_add(DbWeight::get().reads(7 as Weight))
+            .saturating_add(DbWeight::get().writes(6 as Weight))
+    }
+    fn update_vesting_schedules(c: u32) -> Weight {
+        (50000000 as Weight)
+            .saturating_add((2000000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+}